@@ -0,0 +1,5 @@
+use problem::run_main;
+
+fn main() {
+    run_main(&days::all());
+}
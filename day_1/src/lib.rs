@@ -0,0 +1,55 @@
+use anyhow::Result;
+use problem::Problem;
+
+pub struct Day1;
+
+impl Problem for Day1 {
+    const DAY: usize = 1;
+
+    type Input = Vec<i32>;
+    type Params = ();
+    type Shared = ();
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn solve_part_one(
+        input: &Self::Input,
+        _shared: &Self::Shared,
+        _params: &Self::Params,
+    ) -> Result<Self::PartOne> {
+        Ok(input
+            .iter()
+            .zip(input.iter().skip(1))
+            .filter(|(prev, next)| next > prev)
+            .count())
+    }
+
+    fn solve_part_two(
+        input: &Self::Input,
+        _shared: &Self::Shared,
+        _params: &Self::Params,
+    ) -> Result<Self::PartTwo> {
+        Ok(input
+            .iter()
+            .zip(input.iter().skip(3))
+            .filter(|(prev, next)| next > prev)
+            .count())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day1;
+    use problem::testing::{check_round_trip, check_solvers};
+    use proptest::{collection::vec, prelude::*};
+
+    problem::example_test!(Day1, "example_1.txt", part_one = 7, part_two = 5);
+
+    proptest! {
+        #[test]
+        fn round_trip(input in vec(0..10000i32, 0..100)) {
+            check_round_trip(&input);
+            check_solvers::<Day1>(&input);
+        }
+    }
+}
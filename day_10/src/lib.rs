@@ -0,0 +1,128 @@
+use anyhow::{anyhow, Result};
+use problem::Problem;
+
+pub struct Day10;
+
+impl Problem for Day10 {
+    const DAY: usize = 10;
+
+    type Input = Vec<String>;
+    type Params = ();
+    type Shared = ();
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn solve_part_one(
+        input: &Self::Input,
+        _shared: &Self::Shared,
+        _params: &Self::Params,
+    ) -> Result<Self::PartOne> {
+        let mut score = 0;
+        for line in input {
+            let mut stack = Vec::new();
+            for c in line.chars() {
+                match c {
+                    '(' | '[' | '{' | '<' => stack.push(c),
+                    ')' => {
+                        if stack.pop() != Some('(') {
+                            score += 3;
+                            break;
+                        }
+                    }
+                    ']' => {
+                        if stack.pop() != Some('[') {
+                            score += 57;
+                            break;
+                        }
+                    }
+                    '}' => {
+                        if stack.pop() != Some('{') {
+                            score += 1197;
+                            break;
+                        }
+                    }
+                    '>' => {
+                        if stack.pop() != Some('<') {
+                            score += 25137;
+                            break;
+                        }
+                    }
+                    _ => break,
+                }
+            }
+        }
+        Ok(score)
+    }
+
+    fn solve_part_two(
+        input: &Self::Input,
+        _shared: &Self::Shared,
+        _params: &Self::Params,
+    ) -> Result<Self::PartTwo> {
+        let mut scores = Vec::new();
+        'line: for line in input {
+            let mut stack = Vec::new();
+            for c in line.chars() {
+                match c {
+                    '(' | '[' | '{' | '<' => stack.push(c),
+                    ')' => {
+                        if stack.pop() != Some('(') {
+                            continue 'line;
+                        }
+                    }
+                    ']' => {
+                        if stack.pop() != Some('[') {
+                            continue 'line;
+                        }
+                    }
+                    '}' => {
+                        if stack.pop() != Some('{') {
+                            continue 'line;
+                        }
+                    }
+                    '>' => {
+                        if stack.pop() != Some('<') {
+                            continue 'line;
+                        }
+                    }
+                    _ => break,
+                }
+            }
+
+            let mut score = 0;
+            while let Some(c) = stack.pop() {
+                score = score * 5
+                    + match c {
+                        '(' => 1,
+                        '[' => 2,
+                        '{' => 3,
+                        '<' => 4,
+                        _ => break,
+                    };
+            }
+            scores.push(score);
+        }
+        if scores.is_empty() {
+            return Err(anyhow!("No incomplete lines"));
+        }
+        scores.sort_unstable();
+        Ok(scores[scores.len() / 2])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day10;
+    use problem::testing::{check_round_trip, check_solvers};
+    use proptest::{collection::vec, prelude::*};
+
+    problem::example_test!(Day10, "example_1.txt", part_one = 26397, part_two = 288957);
+
+    proptest! {
+        #[test]
+        fn round_trip(input in vec("[()\\[\\]{}<>]{0,24}", 0..20)) {
+            check_round_trip(&input);
+            check_solvers::<Day10>(&input);
+        }
+    }
+}
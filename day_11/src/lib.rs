@@ -0,0 +1,148 @@
+use anyhow::{anyhow, Result};
+use problem::{Frame, Grid, Problem, Renderer, Rgb, Visualize, ADJACENT};
+
+fn update(grid: &mut Grid<u8>) -> usize {
+    for value in grid.cells_mut() {
+        *value += 1;
+    }
+
+    let mut finished = false;
+    while !finished {
+        finished = true;
+        for (x, y) in grid.points() {
+            let value = grid[(x, y)];
+            if value > 9 && value < 100 {
+                grid[(x, y)] = 100;
+                finished = false;
+                for &(dx, dy) in ADJACENT.iter() {
+                    if let Some(neighbor) = grid.offset(x, y, dx, dy) {
+                        grid[neighbor] += 1;
+                    }
+                }
+            }
+        }
+    }
+
+    let mut flashes = 0;
+    for value in grid.cells_mut() {
+        if *value >= 100 {
+            flashes += 1;
+            *value = 0;
+        }
+    }
+    flashes
+}
+
+problem::params! {
+    pub struct Params {
+        steps: usize = 100,
+        max_steps: usize = 10000,
+    }
+}
+
+pub struct Day11;
+
+impl Problem for Day11 {
+    const DAY: usize = 11;
+
+    type Input = Grid<u8>;
+    type Params = Params;
+    type Shared = ();
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn solve_part_one(
+        input: &Self::Input,
+        _shared: &Self::Shared,
+        params: &Self::Params,
+    ) -> Result<Self::PartOne> {
+        let mut grid = input.clone();
+        let mut flashes = 0;
+        for _ in 0..params.steps {
+            flashes += update(&mut grid);
+        }
+        Ok(flashes)
+    }
+
+    fn solve_part_two(
+        input: &Self::Input,
+        _shared: &Self::Shared,
+        params: &Self::Params,
+    ) -> Result<Self::PartTwo> {
+        let mut grid = input.clone();
+        for i in 1..=params.max_steps {
+            problem::check_cancelled()?;
+            if update(&mut grid) == grid.len() {
+                return Ok(i);
+            }
+        }
+        Err(anyhow!(
+            "Octopuses did not all flash within {} steps",
+            params.max_steps
+        ))
+    }
+}
+
+fn frame(grid: &Grid<u8>) -> Frame {
+    grid.map(|&energy| match energy {
+        0 => Rgb::WHITE,
+        _ => Rgb(energy * 12, energy * 12, 40 + energy * 20),
+    })
+}
+
+impl Visualize for Day11 {
+    fn visualize(
+        input: &Self::Input,
+        params: &Self::Params,
+        renderer: &mut dyn Renderer,
+    ) -> Result<()> {
+        let mut grid = input.clone();
+        renderer.render(&frame(&grid))?;
+        for _ in 0..params.max_steps {
+            let flashes = update(&mut grid);
+            renderer.render(&frame(&grid))?;
+            if flashes == grid.len() {
+                return Ok(());
+            }
+        }
+        Err(anyhow!(
+            "Octopuses did not all flash within {} steps",
+            params.max_steps
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Day11, Params};
+    use problem::{
+        testing::{check_round_trip, grid},
+        Grid, Input, Problem,
+    };
+    use proptest::prelude::*;
+
+    problem::example_test!(Day11, "example_1.txt", part_one = 1656, part_two = 195);
+
+    #[test]
+    fn max_steps() {
+        let example = include_str!("../../inputs/day_11/example_1.txt");
+        let input = Grid::parse(example.as_bytes()).unwrap();
+        let params = Params {
+            steps: 100,
+            max_steps: 194,
+        };
+        assert!(Day11::solve_part_two(&input, &(), &params).is_err());
+        let params = Params {
+            max_steps: 195,
+            ..params
+        };
+        assert_eq!(Day11::solve_part_two(&input, &(), &params).unwrap(), 195);
+    }
+
+    proptest! {
+        #[test]
+        fn round_trip(input in grid(0..=9u8, 1..20, 1..20)) {
+            check_round_trip(&input);
+        }
+    }
+}
@@ -0,0 +1,195 @@
+use anyhow::{anyhow, Result};
+use problem::{Locate, Problem};
+use std::collections::{HashMap, VecDeque};
+
+#[derive(Debug)]
+pub struct Node {
+    pub name: String,
+    pub is_big: bool,
+    pub edges: Vec<usize>,
+}
+
+#[derive(Debug)]
+pub struct Graph {
+    pub nodes: Vec<Node>,
+    pub links: Vec<(usize, usize)>,
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Graph {
+    fn new() -> Self {
+        Graph {
+            nodes: vec![
+                Node {
+                    name: "start".to_string(),
+                    is_big: false,
+                    edges: Vec::new(),
+                },
+                Node {
+                    name: "end".to_string(),
+                    is_big: false,
+                    edges: Vec::new(),
+                },
+            ],
+            links: Vec::new(),
+            start: 0,
+            end: 1,
+        }
+    }
+
+    fn insert_node(&mut self, name: &str) -> usize {
+        let result = self.nodes.len();
+        self.nodes.push(Node {
+            name: name.to_string(),
+            is_big: is_uppercase(name),
+            edges: Vec::new(),
+        });
+        result
+    }
+
+    fn insert_edge(&mut self, from: usize, to: usize) {
+        self.nodes[from].edges.push(to);
+        self.nodes[to].edges.push(from);
+        self.links.push((from, to));
+    }
+}
+
+fn is_uppercase(s: &str) -> bool {
+    s.chars().all(|c| c.is_ascii_uppercase())
+}
+
+impl problem::Input for Graph {
+    fn parse<R: std::io::BufRead>(reader: R) -> Result<Self> {
+        let mut name_to_node = HashMap::new();
+        let mut result = Self::new();
+        name_to_node.insert("start".to_string(), result.start);
+        name_to_node.insert("end".to_string(), result.end);
+        for (y, line) in reader.lines().enumerate() {
+            let line = line?;
+            let (a, b) = line
+                .split_once('-')
+                .ok_or_else(|| anyhow!("Expected a link between two caves"))
+                .at_line(y, &line)?;
+            let a_index = *name_to_node
+                .entry(a.to_string())
+                .or_insert_with(|| result.insert_node(a));
+            let b_index = *name_to_node
+                .entry(b.to_string())
+                .or_insert_with(|| result.insert_node(b));
+            result.insert_edge(a_index, b_index);
+        }
+        Ok(result)
+    }
+}
+
+impl problem::Unparse for Graph {
+    fn unparse<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        for &(from, to) in self.links.iter() {
+            writeln!(writer, "{}-{}", self.nodes[from].name, self.nodes[to].name)?;
+        }
+        Ok(())
+    }
+}
+
+pub struct Day12;
+
+impl Problem for Day12 {
+    const DAY: usize = 12;
+
+    type Input = Graph;
+    type Params = ();
+    type Shared = ();
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn solve_part_one(
+        input: &Self::Input,
+        _shared: &Self::Shared,
+        _params: &Self::Params,
+    ) -> Result<Self::PartOne> {
+        let mut paths = VecDeque::new();
+        paths.push_back(vec![input.start]);
+        let mut count = 0;
+        while let Some(path) = paths.pop_front() {
+            problem::check_cancelled()?;
+            let current = *path.last().unwrap();
+            if current == input.end {
+                count += 1;
+            } else {
+                for &next in input.nodes[current].edges.iter() {
+                    if input.nodes[next].is_big || !path.contains(&next) {
+                        let mut new = path.clone();
+                        new.push(next);
+                        paths.push_back(new);
+                    }
+                }
+            }
+        }
+        Ok(count)
+    }
+
+    fn solve_part_two(
+        input: &Self::Input,
+        _shared: &Self::Shared,
+        _params: &Self::Params,
+    ) -> Result<Self::PartTwo> {
+        let mut paths = VecDeque::new();
+        paths.push_back((vec![input.start], false));
+        let mut count = 0;
+        while let Some((path, revisited)) = paths.pop_front() {
+            problem::check_cancelled()?;
+            let current = *path.last().unwrap();
+            if current == input.end {
+                count += 1;
+            } else {
+                for &next in input.nodes[current].edges.iter() {
+                    let is_revisit = !input.nodes[next].is_big && path.contains(&next);
+                    if next != input.start && (!is_revisit || !revisited) {
+                        let mut new = path.clone();
+                        new.push(next);
+                        paths.push_back((new, revisited || is_revisit));
+                    }
+                }
+            }
+        }
+        Ok(count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Day12, Graph};
+    use problem::{
+        testing::{check_round_trip, check_solvers},
+        Input,
+    };
+    use proptest::{collection::vec, prelude::*, sample::select};
+
+    problem::example_test!(small: Day12, "example_small.txt", part_one = 10, part_two = 36);
+    problem::example_test!(medium: Day12, "example_medium.txt", part_one = 19, part_two = 103);
+    problem::example_test!(large: Day12, "example_large.txt", part_one = 226, part_two = 3509);
+
+    fn graph() -> impl Strategy<Value = Graph> {
+        let names = vec!["start", "end", "a", "b", "cd", "A", "BC"];
+        vec((select(names.clone()), select(names)), 0..8).prop_map(|links| {
+            let text = links
+                .into_iter()
+                .filter(|(a, b)| {
+                    a != b
+                        && !(a.starts_with(char::is_uppercase) && b.starts_with(char::is_uppercase))
+                })
+                .map(|(a, b)| format!("{}-{}\n", a, b))
+                .collect::<String>();
+            Graph::parse(text.as_bytes()).unwrap()
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn round_trip(input in graph()) {
+            check_round_trip(&input);
+            check_solvers::<Day12>(&input);
+        }
+    }
+}
@@ -0,0 +1,225 @@
+use ::core::{fmt, str::FromStr};
+use anyhow::{anyhow, Error, Result};
+use problem::{Frame, Problem, Renderer, Rgb, Visualize, CSV};
+
+#[derive(Debug)]
+pub enum Fold {
+    Horizontal(i32),
+    Vertical(i32),
+}
+
+impl Fold {
+    fn transform_point(&self, point: (i32, i32)) -> (i32, i32) {
+        match self {
+            Fold::Horizontal(y) => (point.0, y - (point.1 - y).abs()),
+            Fold::Vertical(x) => (x - (point.0 - x).abs(), point.1),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Input {
+    pub points: Vec<(i32, i32)>,
+    pub folds: Vec<Fold>,
+}
+
+impl FromStr for Fold {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        if let Some(x) = s.strip_prefix("fold along x=") {
+            Ok(Fold::Vertical(x.parse()?))
+        } else if let Some(y) = s.strip_prefix("fold along y=") {
+            Ok(Fold::Horizontal(y.parse()?))
+        } else {
+            Err(anyhow!("Invalid fold instruction"))
+        }
+    }
+}
+
+impl fmt::Display for Fold {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Fold::Vertical(x) => write!(f, "fold along x={}", x),
+            Fold::Horizontal(y) => write!(f, "fold along y={}", y),
+        }
+    }
+}
+
+impl problem::Input for Input {
+    fn parse<R: std::io::BufRead>(reader: R) -> Result<Self> {
+        let (points, folds) = <(Vec<CSV<i32>>, Vec<Fold>)>::parse(reader)?;
+        Ok(Self {
+            points: points
+                .iter()
+                .enumerate()
+                .map(|(i, p)| match p.values() {
+                    &[x, y] => Ok((x, y)),
+                    _ => Err(anyhow!("Expected X and Y coords on line {}", i + 1)),
+                })
+                .collect::<Result<_>>()?,
+            folds,
+        })
+    }
+}
+
+impl problem::Unparse for Input {
+    fn unparse<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        for (x, y) in self.points.iter() {
+            writeln!(writer, "{},{}", x, y)?;
+        }
+        writeln!(writer)?;
+        self.folds.unparse(writer)
+    }
+}
+
+fn fold_points(points: &mut Vec<(i32, i32)>, fold: &Fold) {
+    for point in points {
+        *point = fold.transform_point(*point);
+    }
+}
+
+pub struct Display {
+    pub lines: Vec<Vec<bool>>,
+}
+
+impl fmt::Display for Display {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f)?;
+        for line in self.lines.iter() {
+            for p in line {
+                if *p {
+                    write!(f, "#")?;
+                } else {
+                    write!(f, ".")?;
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+pub struct Day13;
+
+impl Problem for Day13 {
+    const DAY: usize = 13;
+
+    type Input = Input;
+    type Params = ();
+    type Shared = ();
+    type PartOne = usize;
+    type PartTwo = Display;
+
+    fn solve_part_one(
+        input: &Self::Input,
+        _shared: &Self::Shared,
+        _params: &Self::Params,
+    ) -> Result<Self::PartOne> {
+        let fold = input
+            .folds
+            .first()
+            .ok_or_else(|| anyhow!("No fold instructions"))?;
+        let mut points = input.points.clone();
+        fold_points(&mut points, fold);
+        points.sort_unstable();
+        points.dedup();
+        Ok(points.len())
+    }
+
+    fn solve_part_two(
+        input: &Self::Input,
+        _shared: &Self::Shared,
+        _params: &Self::Params,
+    ) -> Result<Self::PartTwo> {
+        let mut points = input.points.clone();
+        for fold in input.folds.iter() {
+            fold_points(&mut points, fold);
+        }
+        points.sort_unstable();
+        points.dedup();
+
+        if points.is_empty() {
+            return Err(anyhow!("No points to display"));
+        }
+        let left = points.iter().map(|p| p.0).min().unwrap();
+        let right = points.iter().map(|p| p.0).max().unwrap();
+        let bottom = points.iter().map(|p| p.1).min().unwrap();
+        let top = points.iter().map(|p| p.1).max().unwrap();
+
+        let mut result = Display { lines: Vec::new() };
+        for _ in bottom..=top {
+            result.lines.push(vec![false; (right - left + 1) as usize]);
+        }
+        for (x, y) in points {
+            result.lines[(y - bottom) as usize][(x - left) as usize] = true;
+        }
+        Ok(result)
+    }
+}
+
+fn frame(points: &[(i32, i32)]) -> Frame {
+    let left = points.iter().map(|p| p.0).min().unwrap_or(0);
+    let right = points.iter().map(|p| p.0).max().unwrap_or(0);
+    let bottom = points.iter().map(|p| p.1).min().unwrap_or(0);
+    let top = points.iter().map(|p| p.1).max().unwrap_or(0);
+
+    let mut frame = Frame::new(
+        (right - left + 1) as usize,
+        (top - bottom + 1) as usize,
+        Rgb::BLACK,
+    );
+    for &(x, y) in points {
+        frame[((x - left) as usize, (y - bottom) as usize)] = Rgb::WHITE;
+    }
+    frame
+}
+
+impl Visualize for Day13 {
+    fn visualize(
+        input: &Self::Input,
+        _params: &Self::Params,
+        renderer: &mut dyn Renderer,
+    ) -> Result<()> {
+        let mut points = input.points.clone();
+        renderer.render(&frame(&points))?;
+        for fold in input.folds.iter() {
+            fold_points(&mut points, fold);
+            renderer.render(&frame(&points))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Day13, Fold, Input};
+    use problem::testing::{check_round_trip, check_solvers};
+    use proptest::{collection::vec, prelude::*};
+
+    problem::example_test!(
+        Day13,
+        "example_1.txt",
+        part_one = 17,
+        part_two = "#####\n#...#\n#...#\n#...#\n#####"
+    );
+
+    fn fold() -> impl Strategy<Value = Fold> {
+        prop_oneof![
+            (0..100i32).prop_map(Fold::Horizontal),
+            (0..100i32).prop_map(Fold::Vertical),
+        ]
+    }
+
+    proptest! {
+        #[test]
+        fn round_trip(
+            points in vec((0..200i32, 0..200i32), 1..50),
+            folds in vec(fold(), 1..10),
+        ) {
+            let input = Input { points, folds };
+            check_round_trip(&input);
+            check_solvers::<Day13>(&input);
+        }
+    }
+}
@@ -0,0 +1,198 @@
+use ::core::{fmt, str::FromStr};
+use ::std::collections::HashMap;
+use anyhow::{anyhow, Error, Result};
+use problem::Problem;
+
+#[derive(Debug)]
+pub struct Rule {
+    pub left: char,
+    pub right: char,
+    pub middle: char,
+}
+
+impl FromStr for Rule {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut captures = problem::scan("{} -> {}", s)?;
+        let pair = captures.next::<String>("pair")?;
+        let middle = captures.next("insertion")?;
+        let mut chars = pair.chars();
+        match (chars.next(), chars.next(), chars.next()) {
+            (Some(left), Some(right), None) => Ok(Self {
+                left,
+                right,
+                middle,
+            }),
+            _ => Err(anyhow!("Expected a pair of elements, found '{}'", pair)),
+        }
+    }
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{} -> {}", self.left, self.right, self.middle)
+    }
+}
+
+#[derive(Debug)]
+pub struct Input {
+    pub template: String,
+    pub rules: Vec<Rule>,
+}
+
+impl problem::Input for Input {
+    fn parse<R: std::io::BufRead>(reader: R) -> Result<Self> {
+        let (template, rules) = <(Vec<String>, Vec<Rule>)>::parse(reader)?;
+        let template = template.concat();
+        if template.is_empty() {
+            return Err(anyhow!("Missing polymer template"));
+        }
+        Ok(Self { template, rules })
+    }
+}
+
+impl problem::Unparse for Input {
+    fn unparse<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writeln!(writer, "{}", self.template)?;
+        writeln!(writer)?;
+        self.rules.unparse(writer)
+    }
+}
+
+fn count_after(input: &Input, c: char, steps: usize) -> usize {
+    let rules = input
+        .rules
+        .iter()
+        .map(|r| ((r.left, r.right), r.middle))
+        .collect();
+    let mut cache = HashMap::new();
+    let mut total = 0;
+    for (left, right) in input.template.chars().zip(input.template.chars().skip(1)) {
+        total += count_after_recurse(left, right, c, steps, &rules, &mut cache);
+    }
+    if input.template.chars().last().unwrap() == c {
+        total += 1;
+    }
+    total
+}
+
+fn count_after_recurse(
+    left: char,
+    right: char,
+    c: char,
+    steps: usize,
+    rules: &HashMap<(char, char), char>,
+    cache: &mut HashMap<(char, char, usize), usize>,
+) -> usize {
+    if steps == 0 {
+        if left == c {
+            1
+        } else {
+            0
+        }
+    } else if let Some(result) = cache.get(&(left, right, steps)) {
+        *result
+    } else if let Some(&middle) = rules.get(&(left, right)) {
+        let result = count_after_recurse(left, middle, c, steps - 1, rules, cache)
+            + count_after_recurse(middle, right, c, steps - 1, rules, cache);
+        cache.insert((left, right, steps), result);
+        result
+    } else if left == c {
+        1
+    } else {
+        0
+    }
+}
+
+fn polymer_strength(input: &Input, steps: usize) -> Result<usize> {
+    let mut chars = Vec::new();
+    for rule in input.rules.iter() {
+        chars.push(rule.left);
+        chars.push(rule.right);
+        chars.push(rule.middle);
+    }
+    chars.sort_unstable();
+    chars.dedup();
+
+    let counts = chars
+        .iter()
+        .map(|&c| count_after(input, c, steps))
+        .collect::<Vec<_>>();
+    let max = counts
+        .iter()
+        .max()
+        .ok_or_else(|| anyhow!("No insertion rules"))?;
+    let min = counts
+        .iter()
+        .min()
+        .ok_or_else(|| anyhow!("No insertion rules"))?;
+    Ok(max - min)
+}
+
+problem::params! {
+    pub struct Params {
+        part_one_steps: usize = 10,
+        part_two_steps: usize = 40,
+    }
+}
+
+pub struct Day14;
+
+impl Problem for Day14 {
+    const DAY: usize = 14;
+
+    type Input = Input;
+    type Params = Params;
+    type Shared = ();
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn solve_part_one(
+        input: &Self::Input,
+        _shared: &Self::Shared,
+        params: &Self::Params,
+    ) -> Result<Self::PartOne> {
+        polymer_strength(input, params.part_one_steps)
+    }
+
+    fn solve_part_two(
+        input: &Self::Input,
+        _shared: &Self::Shared,
+        params: &Self::Params,
+    ) -> Result<Self::PartTwo> {
+        polymer_strength(input, params.part_two_steps)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Day14, Input, Rule};
+    use problem::testing::{check_round_trip, check_solvers};
+    use proptest::{collection::vec, prelude::*};
+
+    problem::example_test!(
+        Day14,
+        "example_1.txt",
+        part_one = 1588,
+        part_two = 2188189693529u64
+    );
+
+    fn rule() -> impl Strategy<Value = Rule> {
+        let element = || proptest::char::range('A', 'D');
+        (element(), element(), element()).prop_map(|(left, right, middle)| Rule {
+            left,
+            right,
+            middle,
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn round_trip(template in "[A-D]{1,20}", rules in vec(rule(), 0..16)) {
+            let input = Input { template, rules };
+            check_round_trip(&input);
+            check_solvers::<Day14>(&input);
+        }
+    }
+}
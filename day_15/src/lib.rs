@@ -0,0 +1,70 @@
+use ::core::cmp::Reverse;
+use ::std::collections::BinaryHeap;
+use anyhow::Result;
+use problem::{Grid, Problem};
+
+fn get_repeated(input: &Grid<u8>, x: usize, y: usize) -> usize {
+    let (width, height) = (input.width(), input.height());
+    (input[(x % width, y % height)] as usize + x / width + y / height - 1) % 9 + 1
+}
+
+fn lowest_risk(input: &Grid<u8>, repeat: usize) -> Result<usize> {
+    let mut risk = Grid::new(input.width() * repeat, input.height() * repeat, usize::MAX);
+    let mut frontier = BinaryHeap::new();
+    frontier.push((Reverse(0), (0, 0)));
+    while let Some((new_risk, (x, y))) = frontier.pop() {
+        if new_risk.0 < risk[(x, y)] {
+            risk[(x, y)] = new_risk.0;
+            for (nx, ny) in risk.neighbors4(x, y) {
+                frontier.push((Reverse(new_risk.0 + get_repeated(input, nx, ny)), (nx, ny)));
+            }
+        }
+    }
+
+    Ok(risk[(risk.width() - 1, risk.height() - 1)])
+}
+
+pub struct Day15;
+
+impl Problem for Day15 {
+    const DAY: usize = 15;
+
+    type Input = Grid<u8>;
+    type Params = ();
+    type Shared = ();
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn solve_part_one(
+        input: &Self::Input,
+        _shared: &Self::Shared,
+        _params: &Self::Params,
+    ) -> Result<Self::PartOne> {
+        lowest_risk(input, 1)
+    }
+
+    fn solve_part_two(
+        input: &Self::Input,
+        _shared: &Self::Shared,
+        _params: &Self::Params,
+    ) -> Result<Self::PartTwo> {
+        lowest_risk(input, 5)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day15;
+    use problem::testing::{check_round_trip, check_solvers, grid};
+    use proptest::prelude::*;
+
+    problem::example_test!(Day15, "example_1.txt", part_one = 40, part_two = 315);
+
+    proptest! {
+        #[test]
+        fn round_trip(input in grid(1..=9u8, 1..20, 1..20)) {
+            check_round_trip(&input);
+            check_solvers::<Day15>(&input);
+        }
+    }
+}
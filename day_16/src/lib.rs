@@ -0,0 +1,393 @@
+use ::core::iter::{ExactSizeIterator, Iterator};
+use anyhow::{anyhow, Result};
+use problem::Problem;
+
+struct Bits<'a> {
+    bytes: &'a [u8],
+    index: usize,
+    bit: u8,
+}
+
+impl<'a> Iterator for Bits<'a> {
+    type Item = bool;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.bit == 8 {
+            self.bit = 0;
+            self.index += 1;
+        }
+
+        if self.index < self.bytes.len() {
+            let result = self.bytes[self.index] & (1 << self.bit);
+            self.bit += 1;
+            Some(result != 0)
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a> ExactSizeIterator for Bits<'a> {
+    fn len(&self) -> usize {
+        (self.bytes.len() - self.index) * 8 - self.bit as usize
+    }
+}
+
+fn next_bit<I: Iterator<Item = bool>>(bits: &mut I) -> Result<bool> {
+    bits.next()
+        .ok_or_else(|| anyhow!("Unexpected end of packet"))
+}
+
+fn int_from_bits<I: Iterator<Item = bool>, const N: usize>(bits: &mut I) -> Result<usize> {
+    let mut result = 0;
+    for _ in 0..N {
+        result <<= 1;
+        if next_bit(bits)? {
+            result |= 1;
+        }
+    }
+    Ok(result)
+}
+
+fn varint_from_bits<I: Iterator<Item = bool>>(bits: &mut I) -> Result<usize> {
+    let mut result = 0;
+    loop {
+        let end = !next_bit(bits)?;
+        result <<= 4;
+        result |= int_from_bits::<_, 4>(bits)?;
+        if end {
+            break;
+        }
+    }
+    Ok(result)
+}
+
+#[derive(Clone, Debug)]
+pub enum Operation {
+    Sum,
+    Product,
+    Minimum,
+    Maximum,
+    GreaterThan,
+    LessThan,
+    EqualTo,
+}
+
+impl Operation {
+    pub fn packet_type(&self) -> usize {
+        match self {
+            Operation::Sum => 0,
+            Operation::Product => 1,
+            Operation::Minimum => 2,
+            Operation::Maximum => 3,
+            Operation::GreaterThan => 5,
+            Operation::LessThan => 6,
+            Operation::EqualTo => 7,
+        }
+    }
+}
+
+fn int_to_bits(bits: &mut Vec<bool>, value: usize, n: usize) {
+    for i in (0..n).rev() {
+        bits.push(value >> i & 1 != 0);
+    }
+}
+
+#[derive(Debug)]
+pub enum Payload {
+    Operator {
+        operation: Operation,
+        packets: Vec<Packet>,
+    },
+    Literal(usize),
+}
+
+impl Payload {
+    fn from_bits<I: ExactSizeIterator<Item = bool>>(bits: &mut I) -> Result<Self> {
+        let packet_type = int_from_bits::<_, 3>(bits)?;
+        if packet_type == 4 {
+            Ok(Payload::Literal(varint_from_bits(bits)?))
+        } else {
+            let mut packets = Vec::new();
+            if !next_bit(bits)? {
+                // 15-bit length-encoded
+                let length = int_from_bits::<_, 15>(bits)?;
+                let initial_len = bits.len();
+                while initial_len - bits.len() < length {
+                    packets.push(Packet::from_bits(bits)?);
+                }
+            } else {
+                // 11-bit number of sub-packets
+                let length = int_from_bits::<_, 11>(bits)?;
+                for _ in 0..length {
+                    packets.push(Packet::from_bits(bits)?);
+                }
+            }
+            Ok(Payload::Operator {
+                operation: match packet_type {
+                    0 => Operation::Sum,
+                    1 => Operation::Product,
+                    2 => Operation::Minimum,
+                    3 => Operation::Maximum,
+                    5 => Operation::GreaterThan,
+                    6 => Operation::LessThan,
+                    7 => Operation::EqualTo,
+                    _ => unreachable!(),
+                },
+                packets,
+            })
+        }
+    }
+
+    pub fn version_sum(&self) -> usize {
+        match self {
+            Payload::Operator {
+                operation: _,
+                packets,
+            } => packets.iter().map(|p| p.version_sum()).sum(),
+            Payload::Literal(_) => 0,
+        }
+    }
+
+    pub fn evaluate(&self) -> Result<usize> {
+        match self {
+            Payload::Operator { operation, packets } => {
+                let mut values = packets
+                    .iter()
+                    .map(|p| p.evaluate())
+                    .collect::<Result<Vec<_>>>()?
+                    .into_iter();
+                let missing = || anyhow!("Missing operand for {:?} packet", operation);
+                let overflow = || anyhow!("{:?} packet overflowed", operation);
+                Ok(match *operation {
+                    Operation::Sum => values
+                        .try_fold(0usize, usize::checked_add)
+                        .ok_or_else(overflow)?,
+                    Operation::Product => values
+                        .try_fold(1usize, usize::checked_mul)
+                        .ok_or_else(overflow)?,
+                    Operation::Minimum => values.min().ok_or_else(missing)?,
+                    Operation::Maximum => values.max().ok_or_else(missing)?,
+                    _ => {
+                        let lhs = values.next().ok_or_else(missing)?;
+                        let rhs = values.next().ok_or_else(missing)?;
+                        match *operation {
+                            Operation::GreaterThan => {
+                                if lhs > rhs {
+                                    1
+                                } else {
+                                    0
+                                }
+                            }
+                            Operation::LessThan => {
+                                if lhs < rhs {
+                                    1
+                                } else {
+                                    0
+                                }
+                            }
+                            Operation::EqualTo => {
+                                if lhs == rhs {
+                                    1
+                                } else {
+                                    0
+                                }
+                            }
+                            _ => unreachable!(),
+                        }
+                    }
+                })
+            }
+            Payload::Literal(value) => Ok(*value),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Packet {
+    pub version: usize,
+    pub payload: Payload,
+}
+
+impl Packet {
+    pub fn from_bits<I: ExactSizeIterator<Item = bool>>(bits: &mut I) -> Result<Self> {
+        let version = int_from_bits::<_, 3>(bits)?;
+        Ok(Self {
+            version,
+            payload: Payload::from_bits(bits)?,
+        })
+    }
+
+    fn to_bits(&self, bits: &mut Vec<bool>) {
+        int_to_bits(bits, self.version, 3);
+        match &self.payload {
+            Payload::Literal(value) => {
+                int_to_bits(bits, 4, 3);
+                let groups = (usize::BITS - value.leading_zeros()).max(1).div_ceil(4);
+                for i in (0..groups).rev() {
+                    bits.push(i != 0);
+                    int_to_bits(bits, value >> (4 * i) & 0xf, 4);
+                }
+            }
+            Payload::Operator { operation, packets } => {
+                int_to_bits(bits, operation.packet_type(), 3);
+                // Always written as an 11-bit number of sub-packets
+                bits.push(true);
+                int_to_bits(bits, packets.len(), 11);
+                for packet in packets.iter() {
+                    packet.to_bits(bits);
+                }
+            }
+        }
+    }
+
+    pub fn version_sum(&self) -> usize {
+        self.version + self.payload.version_sum()
+    }
+
+    pub fn evaluate(&self) -> Result<usize> {
+        self.payload.evaluate()
+    }
+}
+
+fn from_hex(a: u8, b: u8) -> Result<u8> {
+    fn h2b(x: u8) -> Result<u8> {
+        (x as char)
+            .to_digit(16)
+            .map(|d| d as u8)
+            .ok_or_else(|| anyhow!("Invalid hex digit '{}'", x as char))
+    }
+    Ok(h2b(a)?.reverse_bits() >> 4 | h2b(b)?.reverse_bits())
+}
+
+impl problem::Input for Packet {
+    fn parse<R: std::io::BufRead>(mut reader: R) -> Result<Self> {
+        let mut text = String::new();
+        reader.read_to_string(&mut text)?;
+        let text = text.trim();
+        if !text.len().is_multiple_of(2) {
+            return Err(anyhow!("Transmission has an odd number of hex digits"));
+        }
+        let bytes = text
+            .as_bytes()
+            .chunks(2)
+            .map(|pair| from_hex(pair[0], pair[1]))
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut bits = Bits {
+            bytes: &bytes,
+            index: 0,
+            bit: 0,
+        };
+        Packet::from_bits(&mut bits)
+    }
+}
+
+impl problem::Unparse for Packet {
+    fn unparse<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        let mut bits = Vec::new();
+        self.to_bits(&mut bits);
+        bits.resize(bits.len().div_ceil(8) * 8, false);
+        for nibble in bits.chunks(4) {
+            let digit = nibble.iter().fold(0, |d, &b| d << 1 | b as u8);
+            write!(writer, "{:X}", digit)?;
+        }
+        writeln!(writer)
+    }
+}
+
+pub struct Day16;
+
+impl Problem for Day16 {
+    const DAY: usize = 16;
+
+    type Input = Packet;
+    type Params = ();
+    type Shared = ();
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn solve_part_one(
+        input: &Self::Input,
+        _shared: &Self::Shared,
+        _params: &Self::Params,
+    ) -> Result<Self::PartOne> {
+        Ok(input.version_sum())
+    }
+
+    fn solve_part_two(
+        input: &Self::Input,
+        _shared: &Self::Shared,
+        _params: &Self::Params,
+    ) -> Result<Self::PartTwo> {
+        input.evaluate()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Day16, Operation, Packet, Payload};
+    use problem::testing::{check_round_trip, check_solvers};
+    use proptest::{collection::vec, prelude::*};
+
+    problem::example_test!(literal: Day16, "example_literal.txt", part_one = 6, part_two = 2021);
+    problem::example_test!(
+        operator_bits: Day16,
+        "example_operator_bits.txt",
+        part_one = 9,
+        part_two = 1
+    );
+    problem::example_test!(
+        operator_subpackets: Day16,
+        "example_operator_subpackets.txt",
+        part_one = 14,
+        part_two = 3
+    );
+    problem::example_test!(version_sum_16: Day16, "example_16.txt", part_one = 16);
+    problem::example_test!(version_sum_12: Day16, "example_12.txt", part_one = 12);
+    problem::example_test!(version_sum_23: Day16, "example_23.txt", part_one = 23);
+    problem::example_test!(version_sum_31: Day16, "example_31.txt", part_one = 31);
+    problem::example_test!(sum: Day16, input = "C200B40A82", part_two = 3);
+    problem::example_test!(product: Day16, input = "04005AC33890", part_two = 54);
+    problem::example_test!(minimum: Day16, input = "880086C3E88112", part_two = 7);
+    problem::example_test!(maximum: Day16, input = "CE00C43D881120", part_two = 9);
+    problem::example_test!(less_than: Day16, input = "D8005AC2A8F0", part_two = 1);
+    problem::example_test!(greater_than: Day16, input = "F600BC2D8F", part_two = 0);
+    problem::example_test!(equal_to: Day16, input = "9C005AC2F8F0", part_two = 0);
+    problem::example_test!(nested: Day16, input = "9C0141080250320F1802104A08", part_two = 1);
+
+    fn operation() -> impl Strategy<Value = Operation> {
+        prop_oneof![
+            Just(Operation::Sum),
+            Just(Operation::Product),
+            Just(Operation::Minimum),
+            Just(Operation::Maximum),
+            Just(Operation::GreaterThan),
+            Just(Operation::LessThan),
+            Just(Operation::EqualTo),
+        ]
+    }
+
+    fn packet() -> impl Strategy<Value = Packet> {
+        let literal = (0..8usize, any::<usize>()).prop_map(|(version, value)| Packet {
+            version,
+            payload: Payload::Literal(value),
+        });
+        literal.prop_recursive(4, 32, 4, |inner| {
+            (0..8usize, operation(), vec(inner, 1..4)).prop_map(|(version, operation, packets)| {
+                Packet {
+                    version,
+                    payload: Payload::Operator { operation, packets },
+                }
+            })
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn round_trip(input in packet()) {
+            check_round_trip(&input);
+            check_solvers::<Day16>(&input);
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use anyhow::Result;
+use problem::Problem;
+
+#[derive(Debug)]
+pub struct TargetArea {
+    pub left: i32,
+    pub right: i32,
+    pub bottom: i32,
+    pub top: i32,
+}
+
+problem::pattern!(struct TargetArea = "target area: x={}..{}, y={}..{}" {
+    left,
+    right,
+    bottom,
+    top,
+});
+
+impl problem::Input for TargetArea {
+    fn parse<R: std::io::BufRead>(mut reader: R) -> Result<Self> {
+        let mut line = String::new();
+        reader.read_line(&mut line)?;
+        line.trim_end().parse()
+    }
+}
+
+impl problem::Unparse for TargetArea {
+    fn unparse<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writeln!(writer, "{}", self)
+    }
+}
+
+fn collect_initial_velocities(x: i32, y: i32, out: &mut Vec<(i32, i32)>) {
+    let k = (1. + 8. * x as f64).sqrt() as i32;
+    let is_stable = k * k == 8 * x + 1 && k % 2 == 1;
+    let stable_steps = (k - 1) / 2;
+    for s in 1..=stable_steps {
+        let xi = (x + (s * (s - 1)) / 2) / s;
+        let yi = (y + (s * (s - 1)) / 2) / s;
+        if s * xi - s * (s - 1) / 2 == x && s * yi - s * (s - 1) / 2 == y {
+            out.push((xi, yi));
+        }
+    }
+    if is_stable {
+        for s in stable_steps + 1..=-2 * y {
+            let yi = (y + (s * (s - 1)) / 2) / s;
+            if s * yi - s * (s - 1) / 2 == y {
+                out.push((stable_steps, yi));
+            }
+        }
+    }
+}
+
+pub struct Day17;
+
+impl Problem for Day17 {
+    const DAY: usize = 17;
+
+    type Input = TargetArea;
+    type Params = ();
+    type Shared = ();
+    type PartOne = i32;
+    type PartTwo = usize;
+
+    fn solve_part_one(
+        input: &Self::Input,
+        _shared: &Self::Shared,
+        _params: &Self::Params,
+    ) -> Result<Self::PartOne> {
+        Ok(input.bottom * (input.bottom + 1) / 2)
+    }
+
+    fn solve_part_two(
+        input: &Self::Input,
+        _shared: &Self::Shared,
+        _params: &Self::Params,
+    ) -> Result<Self::PartTwo> {
+        let mut initial_velocities = Vec::new();
+        for x in input.left..=input.right {
+            for y in input.bottom..=input.top {
+                collect_initial_velocities(x, y, &mut initial_velocities);
+            }
+        }
+        initial_velocities.sort_unstable();
+        initial_velocities.dedup();
+        Ok(initial_velocities.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Day17, TargetArea};
+    use problem::testing::{check_round_trip, check_solvers};
+    use proptest::prelude::*;
+
+    problem::example_test!(Day17, "example_1.txt", part_one = 45, part_two = 112);
+
+    fn target_area() -> impl Strategy<Value = TargetArea> {
+        (1..100i32, 0..30i32, -100..-1i32, 0..30i32).prop_map(|(left, width, bottom, height)| {
+            TargetArea {
+                left,
+                right: left + width,
+                bottom,
+                top: (bottom + height).min(-1),
+            }
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn round_trip(input in target_area()) {
+            check_round_trip(&input);
+            check_solvers::<Day17>(&input);
+        }
+    }
+}
@@ -0,0 +1,289 @@
+use ::anyhow::{anyhow, Error, Result};
+use ::core::{
+    ops::{Add, Sub},
+    str::FromStr,
+};
+use ::std::collections::HashMap;
+use problem::{Problem, Sections};
+use std::collections::VecDeque;
+
+#[derive(Clone, Copy)]
+pub enum Axis {
+    X,
+    Y,
+    Z,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Vector {
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
+}
+
+impl Vector {
+    const ZERO: Self = Self { x: 0, y: 0, z: 0 };
+
+    pub fn rotate(self, axis: Axis) -> Self {
+        match axis {
+            Axis::X => self.rotate_x(),
+            Axis::Y => self.rotate_y(),
+            Axis::Z => self.rotate_z(),
+        }
+    }
+
+    pub fn rotate_x(self) -> Self {
+        Self {
+            x: self.x,
+            y: self.z,
+            z: -self.y,
+        }
+    }
+
+    pub fn rotate_y(self) -> Self {
+        Self {
+            x: -self.z,
+            y: self.y,
+            z: self.x,
+        }
+    }
+
+    pub fn rotate_z(self) -> Self {
+        Self {
+            x: self.y,
+            y: -self.x,
+            z: self.z,
+        }
+    }
+}
+
+impl Vector {
+    pub fn abs(self) -> Self {
+        Self {
+            x: self.x.abs(),
+            y: self.y.abs(),
+            z: self.z.abs(),
+        }
+    }
+}
+
+impl Add for Vector {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+            z: self.z + rhs.z,
+        }
+    }
+}
+
+impl Sub for Vector {
+    type Output = Vector;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+            z: self.z - rhs.z,
+        }
+    }
+}
+
+impl FromStr for Vector {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut components = s.split(',');
+        Ok(Self {
+            x: components
+                .next()
+                .ok_or(anyhow!("Missing X component"))?
+                .parse()?,
+            y: components
+                .next()
+                .ok_or(anyhow!("Missing Y component"))?
+                .parse()?,
+            z: components
+                .next()
+                .ok_or(anyhow!("Missing Z component"))?
+                .parse()?,
+        })
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Scanner {
+    pub beacons: Vec<Vector>,
+}
+
+impl Scanner {
+    pub fn try_orient(&self, other: &mut Scanner) -> Option<Vector> {
+        const ROTATION_PATH: [Axis; 6] = [Axis::X, Axis::X, Axis::Z, Axis::X, Axis::X, Axis::Z];
+
+        for axis in ROTATION_PATH {
+            for _ in 0..4 {
+                let mut offset_counts = HashMap::new();
+                for &a in self.beacons.iter() {
+                    for &b in other.beacons.iter() {
+                        *offset_counts.entry(a - b).or_insert(0) += 1;
+                    }
+                }
+                if let Some(offset) = offset_counts
+                    .iter()
+                    .filter_map(|(&o, &n)| if n >= 12 { Some(o) } else { None })
+                    .next()
+                {
+                    return Some(offset);
+                }
+                other.beacons.iter_mut().for_each(|p| *p = p.rotate_y());
+            }
+            other.beacons.iter_mut().for_each(|p| *p = p.rotate(axis));
+        }
+
+        None
+    }
+}
+
+#[derive(Debug)]
+pub struct Input {
+    pub scanners: Vec<Scanner>,
+}
+
+impl problem::Input for Scanner {
+    fn parse<R: std::io::BufRead>(reader: R) -> Result<Self> {
+        let mut lines = reader.lines();
+        lines
+            .next()
+            .ok_or_else(|| anyhow!("Missing scanner header"))??;
+        let beacons = lines.map(|l| l?.parse()).collect::<Result<Vec<_>>>()?;
+        Ok(Self { beacons })
+    }
+}
+
+impl problem::Input for Input {
+    fn parse<R: std::io::BufRead>(reader: R) -> Result<Self> {
+        Ok(Self {
+            scanners: Sections::<Scanner>::parse(reader)?.into_values(),
+        })
+    }
+}
+
+impl problem::Unparse for Input {
+    fn unparse<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        for (i, scanner) in self.scanners.iter().enumerate() {
+            if i != 0 {
+                writeln!(writer)?;
+            }
+            writeln!(writer, "--- scanner {} ---", i)?;
+            for b in scanner.beacons.iter() {
+                writeln!(writer, "{},{},{}", b.x, b.y, b.z)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+pub struct Day19;
+
+impl Problem for Day19 {
+    const DAY: usize = 19;
+
+    type Input = Input;
+    type Params = ();
+    type Shared = Vec<(Scanner, Vector)>;
+    type PartOne = usize;
+    type PartTwo = i32;
+
+    fn prepare(input: &Self::Input, _params: &Self::Params) -> Result<Self::Shared> {
+        let mut unoriented = input
+            .scanners
+            .iter()
+            .cloned()
+            .map(|s| (s, 0))
+            .collect::<VecDeque<_>>();
+
+        let (first, _) = unoriented
+            .pop_front()
+            .ok_or_else(|| anyhow!("No scanners"))?;
+        let mut oriented = vec![(first, Vector::ZERO)];
+        'orient: while let Some((mut u, n)) = unoriented.pop_front() {
+            if n == oriented.len() && unoriented.iter().all(|&(_, m)| m == oriented.len()) {
+                return Err(anyhow!(
+                    "{} scanners do not overlap any oriented scanner",
+                    unoriented.len() + 1
+                ));
+            }
+            for i in n..oriented.len() {
+                let (o, offset) = &oriented[i];
+                let offset = *offset;
+                if let Some(rel_offset) = o.try_orient(&mut u) {
+                    oriented.push((u, offset + rel_offset));
+                    continue 'orient;
+                }
+            }
+            unoriented.push_back((u, oriented.len()));
+        }
+
+        Ok(oriented)
+    }
+
+    fn solve_part_one(
+        _input: &Self::Input,
+        oriented: &Self::Shared,
+        _params: &Self::Params,
+    ) -> Result<Self::PartOne> {
+        let mut beacons = Vec::new();
+        for (s, o) in oriented.iter() {
+            for &b in s.beacons.iter() {
+                beacons.push(b + *o);
+            }
+        }
+
+        beacons.sort_unstable();
+        beacons.dedup();
+
+        Ok(beacons.len())
+    }
+
+    fn solve_part_two(
+        _input: &Self::Input,
+        oriented: &Self::Shared,
+        _params: &Self::Params,
+    ) -> Result<Self::PartTwo> {
+        let mut max = 0;
+        for i in 0..oriented.len() {
+            for j in i + 1..oriented.len() {
+                let d = oriented[i].1 - oriented[j].1;
+                max = i32::max(max, d.x.abs() + d.y.abs() + d.z.abs());
+            }
+        }
+
+        Ok(max)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Day19, Input, Scanner, Vector};
+    use problem::testing::{check_round_trip, check_solvers};
+    use proptest::{collection::vec, prelude::*};
+
+    problem::example_test!(Day19, "example_1.txt", part_one = 79, part_two = 3621);
+
+    fn scanner() -> impl Strategy<Value = Scanner> {
+        let vector =
+            (-1000..1000, -1000..1000, -1000..1000).prop_map(|(x, y, z)| Vector { x, y, z });
+        vec(vector, 0..20).prop_map(|beacons| Scanner { beacons })
+    }
+
+    proptest! {
+        #[test]
+        fn round_trip(scanners in vec(scanner(), 1..5)) {
+            let input = Input { scanners };
+            check_round_trip(&input);
+            check_solvers::<Day19>(&input);
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use anyhow::Result;
+use problem::Problem;
+
+#[derive(Debug)]
+pub enum Command {
+    Forward(i32),
+    Down(i32),
+    Up(i32),
+}
+
+problem::pattern!(
+    enum Command {
+        Forward(amount) = "forward {}",
+        Down(amount) = "down {}",
+        Up(amount) = "up {}",
+    }
+);
+
+pub struct Day2;
+
+impl Problem for Day2 {
+    const DAY: usize = 2;
+
+    type Input = Vec<Command>;
+    type Params = ();
+    type Shared = ();
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn solve_part_one(
+        input: &Self::Input,
+        _shared: &Self::Shared,
+        _params: &Self::Params,
+    ) -> Result<Self::PartOne> {
+        let (pos, depth) = input
+            .iter()
+            .fold((0, 0), |(pos, depth), command| match command {
+                Command::Forward(amount) => (pos + amount, depth),
+                Command::Down(amount) => (pos, depth + amount),
+                Command::Up(amount) => (pos, depth - amount),
+            });
+        Ok(pos * depth)
+    }
+
+    fn solve_part_two(
+        input: &Self::Input,
+        _shared: &Self::Shared,
+        _params: &Self::Params,
+    ) -> Result<Self::PartTwo> {
+        let (pos, depth, _) =
+            input
+                .iter()
+                .fold((0, 0, 0), |(pos, depth, aim), command| match command {
+                    Command::Forward(amount) => (pos + amount, depth + aim * amount, aim),
+                    Command::Down(amount) => (pos, depth, aim + amount),
+                    Command::Up(amount) => (pos, depth, aim - amount),
+                });
+        Ok(pos * depth)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Command;
+    use super::Day2;
+    use problem::testing::{check_round_trip, check_solvers};
+    use proptest::{collection::vec, prelude::*};
+
+    problem::example_test!(Day2, "example_1.txt", part_one = 150, part_two = 900);
+
+    fn command() -> impl Strategy<Value = Command> {
+        prop_oneof![
+            (0..10i32).prop_map(Command::Forward),
+            (0..10i32).prop_map(Command::Down),
+            (0..10i32).prop_map(Command::Up),
+        ]
+    }
+
+    proptest! {
+        #[test]
+        fn round_trip(input in vec(command(), 0..50)) {
+            check_round_trip(&input);
+            check_solvers::<Day2>(&input);
+        }
+    }
+}
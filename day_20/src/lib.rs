@@ -0,0 +1,186 @@
+use ::anyhow::{anyhow, Result};
+use ::bitvec::prelude::*;
+use problem::{Grid, Problem, Renderer, Rgb, Visualize};
+
+fn neighbor_index(grid: &Grid<bool>, x: usize, y: usize, rest_lit: bool) -> usize {
+    let mut result = 0;
+    for dy in -1..=1 {
+        for dx in -1..=1 {
+            result <<= 1;
+            let lit = grid.offset(x, y, dx, dy).map_or(rest_lit, |n| grid[n]);
+            result |= if lit { 1 } else { 0 };
+        }
+    }
+    result
+}
+
+pub fn step(grid: &Grid<bool>, lookup: &BitSlice, rest_lit: bool, out: &mut Grid<bool>) -> bool {
+    assert_eq!(grid.width(), out.width());
+    assert_eq!(grid.height(), out.height());
+
+    for (x, y) in grid.points() {
+        out[(x, y)] = lookup[neighbor_index(grid, x, y, rest_lit)];
+    }
+
+    if rest_lit {
+        lookup[0b1_1111_1111]
+    } else {
+        lookup[0b0_0000_0000]
+    }
+}
+
+#[derive(Debug)]
+pub struct Input {
+    pub lookup: BitArr!(for 512),
+    pub initial: Grid<bool>,
+}
+
+impl problem::Input for Input {
+    fn parse<R: std::io::BufRead>(reader: R) -> Result<Self> {
+        let (algorithm, initial) = <(Grid<bool>, Grid<bool>)>::parse(reader)?;
+        if algorithm.len() != 512 {
+            return Err(anyhow!(
+                "Enhancement algorithm has {} entries, expected 512",
+                algorithm.len()
+            ));
+        }
+
+        let mut lookup = bitarr![0; 512];
+        for (i, &lit) in algorithm.cells().iter().enumerate() {
+            lookup.set(i, lit);
+        }
+
+        Ok(Self { lookup, initial })
+    }
+}
+
+impl problem::Unparse for Input {
+    fn unparse<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        let algorithm = Grid::from_cells(512, 1, self.lookup.iter().map(|b| *b).collect())
+            .expect("Lookup table has 512 entries");
+        algorithm.unparse(writer)?;
+        writeln!(writer)?;
+        self.initial.unparse(writer)
+    }
+}
+
+pub fn simulate(input: &Input, steps: usize) -> Result<Grid<bool>> {
+    simulate_with(input, steps, |_| Ok(()))
+}
+
+fn simulate_with(
+    input: &Input,
+    steps: usize,
+    mut on_step: impl FnMut(&Grid<bool>) -> Result<()>,
+) -> Result<Grid<bool>> {
+    let border = steps + 1;
+    let mut result = Grid::new(
+        input.initial.width() + 2 * border,
+        input.initial.height() + 2 * border,
+        false,
+    );
+    let mut output = result.clone();
+
+    // Initial setup
+    for (x, y) in input.initial.points() {
+        result[(x + border, y + border)] = input.initial[(x, y)];
+    }
+
+    // Update
+    on_step(&result)?;
+    let mut rest_lit = false;
+    for _ in 0..steps {
+        rest_lit = step(&result, &input.lookup, rest_lit, &mut output);
+        ::core::mem::swap(&mut result, &mut output);
+        on_step(&result)?;
+    }
+
+    Ok(result)
+}
+
+problem::params! {
+    pub struct Params {
+        part_one_steps: usize = 2,
+        part_two_steps: usize = 50,
+    }
+}
+
+pub struct Day20;
+
+impl Problem for Day20 {
+    const DAY: usize = 20;
+
+    type Input = Input;
+    type Params = Params;
+    type Shared = ();
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn solve_part_one(
+        input: &Self::Input,
+        _shared: &Self::Shared,
+        params: &Self::Params,
+    ) -> Result<Self::PartOne> {
+        Ok(simulate(input, params.part_one_steps)?
+            .cells()
+            .iter()
+            .filter(|x| **x)
+            .count())
+    }
+
+    fn solve_part_two(
+        input: &Self::Input,
+        _shared: &Self::Shared,
+        params: &Self::Params,
+    ) -> Result<Self::PartTwo> {
+        Ok(simulate(input, params.part_two_steps)?
+            .cells()
+            .iter()
+            .filter(|x| **x)
+            .count())
+    }
+}
+
+impl Visualize for Day20 {
+    fn visualize(
+        input: &Self::Input,
+        params: &Self::Params,
+        renderer: &mut dyn Renderer,
+    ) -> Result<()> {
+        simulate_with(input, params.part_two_steps, |grid| {
+            renderer.render(&grid.map(|&lit| if lit { Rgb::WHITE } else { Rgb::BLACK }))
+        })?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day20;
+    use super::Input;
+    use bitvec::prelude::*;
+    use problem::testing::{check_round_trip, check_solvers, grid};
+    use proptest::{collection::vec, prelude::*};
+
+    problem::example_test!(Day20, "example_1.txt", part_one = 35, part_two = 3351);
+
+    fn input() -> impl Strategy<Value = Input> {
+        (vec(any::<bool>(), 512), grid(any::<bool>(), 1..10, 1..10)).prop_map(|(bits, initial)| {
+            let mut lookup = bitarr![0; 512];
+            for (i, bit) in bits.into_iter().enumerate() {
+                lookup.set(i, bit);
+            }
+            Input { lookup, initial }
+        })
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(16))]
+
+        #[test]
+        fn round_trip(input in input()) {
+            check_round_trip(&input);
+            check_solvers::<Day20>(&input);
+        }
+    }
+}
@@ -0,0 +1,212 @@
+use std::collections::HashMap;
+
+use anyhow::{anyhow, Result};
+use problem::Problem;
+
+#[derive(Debug)]
+pub struct Input {
+    pub p1_start: usize,
+    pub p2_start: usize,
+}
+
+fn starting_position(line: Option<std::io::Result<String>>, player: usize) -> Result<usize> {
+    let line = line.ok_or_else(|| anyhow!("Missing player {} starting position", player))??;
+    let mut captures = problem::scan("Player {} starting position: {}", &line)?;
+    let found = captures.next::<usize>("player")?;
+    if found != player {
+        return Err(anyhow!(
+            "Expected player {}, found player {}",
+            player,
+            found
+        ));
+    }
+    let position = captures.next("starting position")?;
+    if !(1..=10).contains(&position) {
+        return Err(anyhow!(
+            "Player {} starting position {} is not between 1 and 10",
+            player,
+            position
+        ));
+    }
+    Ok(position)
+}
+
+impl problem::Input for Input {
+    fn parse<R: std::io::BufRead>(reader: R) -> Result<Self> {
+        let mut lines = reader.lines();
+        Ok(Self {
+            p1_start: starting_position(lines.next(), 1)?,
+            p2_start: starting_position(lines.next(), 2)?,
+        })
+    }
+}
+
+impl problem::Unparse for Input {
+    fn unparse<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writeln!(writer, "Player 1 starting position: {}", self.p1_start)?;
+        writeln!(writer, "Player 2 starting position: {}", self.p2_start)
+    }
+}
+
+pub struct Day21;
+
+impl Problem for Day21 {
+    const DAY: usize = 21;
+
+    type Input = Input;
+    type Params = ();
+    type Shared = ();
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn solve_part_one(
+        input: &Self::Input,
+        _shared: &Self::Shared,
+        _params: &Self::Params,
+    ) -> Result<Self::PartOne> {
+        let mut p1_score = 0;
+        let mut p2_score = 0;
+        let mut p1_pos = input.p1_start - 1;
+        let mut p2_pos = input.p2_start - 1;
+        let mut rolls = 0;
+
+        fn roll(rolls: usize) -> usize {
+            let r1 = rolls % 100;
+            let r2 = (rolls + 1) % 100;
+            let r3 = (rolls + 2) % 100;
+            r1 + r2 + r3 + 3
+        }
+
+        let losing_score = loop {
+            p1_pos = (p1_pos + roll(rolls)) % 10;
+            rolls += 3;
+            p1_score += p1_pos + 1;
+
+            if p1_score >= 1000 {
+                break p2_score;
+            }
+
+            p2_pos = (p2_pos + roll(rolls)) % 10;
+            rolls += 3;
+            p2_score += p2_pos + 1;
+
+            if p2_score >= 1000 {
+                break p1_score;
+            }
+        };
+
+        Ok(losing_score * rolls)
+    }
+
+    fn solve_part_two(
+        input: &Self::Input,
+        _shared: &Self::Shared,
+        _params: &Self::Params,
+    ) -> Result<Self::PartTwo> {
+        use ::core::ops::{Add, Mul};
+
+        #[derive(Clone, Copy)]
+        struct Score(usize, usize);
+
+        impl Score {
+            fn transpose(self) -> Self {
+                Self(self.1, self.0)
+            }
+        }
+
+        impl Add for Score {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self::Output {
+                Score(self.0 + rhs.0, self.1 + rhs.1)
+            }
+        }
+
+        impl Mul<usize> for Score {
+            type Output = Self;
+
+            fn mul(self, rhs: usize) -> Self::Output {
+                Score(rhs * self.0, rhs * self.1)
+            }
+        }
+
+        #[inline]
+        fn simulate(
+            s1: usize,
+            p1: usize,
+            s2: usize,
+            p2: usize,
+            r: usize,
+            cache: &mut HashMap<(usize, usize, usize, usize), Score>,
+        ) -> Score {
+            let new_pos = (p1 + r) % 10;
+            let new_score = s1 + new_pos + 1;
+            count_wins(s2, p2, new_score, new_pos, cache).transpose()
+        }
+
+        #[inline]
+        fn count_wins(
+            s1: usize,
+            p1: usize,
+            s2: usize,
+            p2: usize,
+            cache: &mut HashMap<(usize, usize, usize, usize), Score>,
+        ) -> Score {
+            if let Some(result) = cache.get(&(s1, p1, s2, p2)) {
+                *result
+            } else if s1 >= 21 {
+                Score(1, 0)
+            } else if s2 >= 21 {
+                Score(0, 1)
+            } else {
+                // p1 rolls:
+                // - 3 in 1/27 cases
+                // - 4 in 3/27 cases
+                // - 5 in 6/27 cases
+                // - 6 in 7/27 cases
+                // - 7 in 6/27 cases
+                // - 8 in 3/27 cases
+                // - 9 in 1/27 cases
+                let result = simulate(s1, p1, s2, p2, 3, cache)
+                    + simulate(s1, p1, s2, p2, 4, cache) * 3
+                    + simulate(s1, p1, s2, p2, 5, cache) * 6
+                    + simulate(s1, p1, s2, p2, 6, cache) * 7
+                    + simulate(s1, p1, s2, p2, 7, cache) * 6
+                    + simulate(s1, p1, s2, p2, 8, cache) * 3
+                    + simulate(s1, p1, s2, p2, 9, cache);
+                cache.insert((s1, p1, s2, p2), result);
+                result
+            }
+        }
+
+        let mut cache = HashMap::new();
+        let Score(p1_wins, p2_wins) =
+            count_wins(0, input.p1_start - 1, 0, input.p2_start - 1, &mut cache);
+        Ok(usize::max(p1_wins, p2_wins))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Day21, Input};
+    use problem::testing::{check_round_trip, check_solvers};
+    use proptest::prelude::*;
+
+    problem::example_test!(
+        Day21,
+        "example_1.txt",
+        part_one = 739785,
+        part_two = 444356092776315u64
+    );
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(16))]
+
+        #[test]
+        fn round_trip(p1_start in 1..=10usize, p2_start in 1..=10usize) {
+            let input = Input { p1_start, p2_start };
+            check_round_trip(&input);
+            check_solvers::<Day21>(&input);
+        }
+    }
+}
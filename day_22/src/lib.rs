@@ -0,0 +1,250 @@
+use ::anyhow::{Error, Result};
+use ::bitvec::prelude::*;
+use ::std::{fmt, iter::IntoIterator, str::FromStr};
+use problem::Problem;
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Range {
+    pub lower: i32,
+    pub upper: i32,
+}
+
+impl Range {
+    pub fn and(self, other: Self) -> Option<Self> {
+        let lower = i32::max(self.lower, other.lower);
+        let upper = i32::min(self.upper, other.upper);
+        if lower > upper {
+            None
+        } else {
+            Some(Self { lower, upper })
+        }
+    }
+}
+
+impl IntoIterator for Range {
+    type Item = i32;
+    type IntoIter = ::std::ops::RangeInclusive<i32>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.lower..=self.upper
+    }
+}
+
+problem::pattern!(struct Range = "{}..{}" { lower, upper });
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Region {
+    pub ranges: [Range; 3],
+}
+
+impl Region {
+    pub fn and(self, other: Self) -> Option<Self> {
+        self.ranges[0].and(other.ranges[0]).and_then(|x| {
+            self.ranges[1].and(other.ranges[1]).and_then(|y| {
+                self.ranges[2]
+                    .and(other.ranges[2])
+                    .map(|z| Self { ranges: [x, y, z] })
+            })
+        })
+    }
+}
+
+impl FromStr for Region {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut captures = problem::scan("x={},y={},z={}", s)?;
+        Ok(Self {
+            ranges: [
+                captures.next("x range")?,
+                captures.next("y range")?,
+                captures.next("z range")?,
+            ],
+        })
+    }
+}
+
+impl fmt::Display for Region {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let [x, y, z] = &self.ranges;
+        write!(f, "x={},y={},z={}", x, y, z)
+    }
+}
+
+#[derive(Debug)]
+pub struct Operation {
+    pub value: bool,
+    pub region: Region,
+}
+
+impl FromStr for Operation {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (index, mut captures) = problem::scan_any(&["on {}", "off {}"], s)?;
+        Ok(Self {
+            value: index == 0,
+            region: captures.next("region")?,
+        })
+    }
+}
+
+impl fmt::Display for Operation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value = if self.value { "on" } else { "off" };
+        write!(f, "{} {}", value, self.region)
+    }
+}
+
+fn count_ones(operations: &[Operation]) -> usize {
+    if operations.is_empty() {
+        return 0;
+    }
+
+    let mut breakpoints = [Vec::new(), Vec::new(), Vec::new()];
+    for (i, breakpoint) in breakpoints.iter_mut().enumerate() {
+        *breakpoint = operations
+            .iter()
+            .flat_map(|o| [o.region.ranges[i].lower, o.region.ranges[i].upper + 1])
+            .collect();
+        breakpoint.sort_unstable();
+        breakpoint.dedup();
+    }
+
+    let size_x = breakpoints[0].len() - 1;
+    let size_y = breakpoints[1].len() - 1;
+    let size_z = breakpoints[2].len() - 1;
+    let area = size_x * size_y * size_z;
+    let mut grid = bitvec![0; area];
+
+    for operation in operations.iter() {
+        let op_x_lower = breakpoints[0]
+            .binary_search(&operation.region.ranges[0].lower)
+            .unwrap();
+        let op_x_upper = breakpoints[0]
+            .binary_search(&(operation.region.ranges[0].upper + 1))
+            .unwrap();
+        let op_y_lower = breakpoints[1]
+            .binary_search(&operation.region.ranges[1].lower)
+            .unwrap();
+        let op_y_upper = breakpoints[1]
+            .binary_search(&(operation.region.ranges[1].upper + 1))
+            .unwrap();
+        let op_z_lower = breakpoints[2]
+            .binary_search(&operation.region.ranges[2].lower)
+            .unwrap();
+        let op_z_upper = breakpoints[2]
+            .binary_search(&(operation.region.ranges[2].upper + 1))
+            .unwrap();
+        for z in op_z_lower..op_z_upper {
+            for y in op_y_lower..op_y_upper {
+                for x in op_x_lower..op_x_upper {
+                    let index = x + size_x * (y + size_y * z);
+                    grid.set(index, operation.value);
+                }
+            }
+        }
+    }
+
+    let mut total = 0;
+    for z in 0..size_z {
+        for y in 0..size_y {
+            for x in 0..size_x {
+                let index = x + size_x * (y + size_y * z);
+                if grid[index] {
+                    total += (breakpoints[0][x + 1] - breakpoints[0][x]) as usize
+                        * (breakpoints[1][y + 1] - breakpoints[1][y]) as usize
+                        * (breakpoints[2][z + 1] - breakpoints[2][z]) as usize;
+                }
+            }
+        }
+    }
+
+    total
+}
+
+problem::params! {
+    pub struct Params {
+        init_region: i32 = 50,
+    }
+}
+
+pub struct Day22;
+
+impl Problem for Day22 {
+    const DAY: usize = 22;
+
+    type Input = Vec<Operation>;
+    type Params = Params;
+    type Shared = ();
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn solve_part_one(
+        input: &Self::Input,
+        _shared: &Self::Shared,
+        params: &Self::Params,
+    ) -> Result<Self::PartOne> {
+        let bound = Range {
+            lower: -params.init_region,
+            upper: params.init_region,
+        };
+        let init_region = Region { ranges: [bound; 3] };
+        let mut ops = Vec::new();
+        for op in input {
+            if let Some(region) = op.region.and(init_region) {
+                ops.push(Operation {
+                    value: op.value,
+                    region,
+                });
+            }
+        }
+
+        Ok(count_ones(&ops))
+    }
+
+    fn solve_part_two(
+        input: &Self::Input,
+        _shared: &Self::Shared,
+        _params: &Self::Params,
+    ) -> Result<Self::PartTwo> {
+        Ok(count_ones(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Day22, Operation, Range, Region};
+    use problem::testing::{check_round_trip, check_solvers};
+    use proptest::{collection::vec, prelude::*};
+
+    problem::example_test!(small: Day22, "example_small.txt", part_one = 590784);
+    problem::example_test!(
+        medium: Day22,
+        "example_medium.txt",
+        part_one = 474140,
+        part_two = 2758514936282235u64
+    );
+
+    fn range() -> impl Strategy<Value = Range> {
+        (-100..100i32, 0..100i32).prop_map(|(lower, size)| Range {
+            lower,
+            upper: lower + size,
+        })
+    }
+
+    fn operation() -> impl Strategy<Value = Operation> {
+        (any::<bool>(), [range(), range(), range()]).prop_map(|(value, ranges)| Operation {
+            value,
+            region: Region { ranges },
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn round_trip(input in vec(operation(), 0..10)) {
+            check_round_trip(&input);
+            check_solvers::<Day22>(&input);
+        }
+    }
+}
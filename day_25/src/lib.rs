@@ -0,0 +1,163 @@
+use anyhow::{anyhow, Result};
+use problem::{Frame, Grid, Problem, Renderer, Rgb, Visualize};
+
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Cell {
+    Empty,
+    East,
+    South,
+    EastMove,
+    SouthMove,
+}
+
+impl problem::Cell for Cell {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '.' => Some(Cell::Empty),
+            '>' => Some(Cell::East),
+            'v' => Some(Cell::South),
+            _ => None,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        match self {
+            Cell::Empty => '.',
+            Cell::East | Cell::EastMove => '>',
+            Cell::South | Cell::SouthMove => 'v',
+        }
+    }
+}
+
+fn mark(grid: &mut Grid<Cell>, from: Cell, to: Cell, dx: isize, dy: isize) {
+    for (x, y) in grid.points() {
+        if grid[(x, y)] == from && grid[grid.wrapping_offset(x, y, dx, dy)] == Cell::Empty {
+            grid[(x, y)] = to;
+        }
+    }
+}
+
+fn apply(grid: &mut Grid<Cell>, from: Cell, to: Cell, dx: isize, dy: isize) -> usize {
+    let mut moved = 0;
+    for (x, y) in grid.points() {
+        if grid[(x, y)] == from {
+            grid[(x, y)] = Cell::Empty;
+            let next = grid.wrapping_offset(x, y, dx, dy);
+            grid[next] = to;
+            moved += 1;
+        }
+    }
+    moved
+}
+
+pub fn step(grid: &mut Grid<Cell>) -> usize {
+    mark(grid, Cell::East, Cell::EastMove, 1, 0);
+    let mut moved = apply(grid, Cell::EastMove, Cell::East, 1, 0);
+    mark(grid, Cell::South, Cell::SouthMove, 0, 1);
+    moved += apply(grid, Cell::SouthMove, Cell::South, 0, 1);
+    moved
+}
+
+problem::params! {
+    pub struct Params {
+        max_steps: usize = 10000,
+    }
+}
+
+pub struct Day25;
+
+impl Problem for Day25 {
+    const DAY: usize = 25;
+
+    type Input = Grid<Cell>;
+    type Params = Params;
+    type Shared = ();
+    type PartOne = usize;
+    type PartTwo = problem::Unimplemented;
+
+    fn solve_part_one(
+        input: &Self::Input,
+        _shared: &Self::Shared,
+        params: &Self::Params,
+    ) -> Result<Self::PartOne> {
+        let mut grid = input.clone();
+        for i in 1..=params.max_steps {
+            problem::check_cancelled()?;
+            if step(&mut grid) == 0 {
+                return Ok(i);
+            }
+        }
+        Err(anyhow!(
+            "Sea cucumbers were still moving after {} steps",
+            params.max_steps
+        ))
+    }
+
+    fn solve_part_two(
+        _input: &Self::Input,
+        _shared: &Self::Shared,
+        _params: &Self::Params,
+    ) -> Result<Self::PartTwo> {
+        Ok(problem::Unimplemented)
+    }
+}
+
+fn frame(grid: &Grid<Cell>) -> Frame {
+    grid.map(|cell| match cell {
+        Cell::Empty => Rgb(0, 20, 60),
+        Cell::East | Cell::EastMove => Rgb(240, 140, 60),
+        Cell::South | Cell::SouthMove => Rgb(120, 220, 120),
+    })
+}
+
+impl Visualize for Day25 {
+    fn visualize(
+        input: &Self::Input,
+        params: &Self::Params,
+        renderer: &mut dyn Renderer,
+    ) -> Result<()> {
+        let mut grid = input.clone();
+        renderer.render(&frame(&grid))?;
+        for _ in 0..params.max_steps {
+            let moved = step(&mut grid);
+            renderer.render(&frame(&grid))?;
+            if moved == 0 {
+                return Ok(());
+            }
+        }
+        Err(anyhow!(
+            "Sea cucumbers were still moving after {} steps",
+            params.max_steps
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Cell, Day25, Params};
+    use problem::{
+        testing::{check_round_trip, grid},
+        Grid, Input, Problem,
+    };
+    use proptest::{prelude::*, sample::select};
+
+    problem::example_test!(Day25, "example_1.txt", part_one = 58);
+
+    #[test]
+    fn max_steps() {
+        let example = include_str!("../../inputs/day_25/example_1.txt");
+        let input = Grid::parse(example.as_bytes()).unwrap();
+        let params = Params { max_steps: 57 };
+        assert!(Day25::solve_part_one(&input, &(), &params).is_err());
+        let params = Params { max_steps: 58 };
+        assert_eq!(Day25::solve_part_one(&input, &(), &params).unwrap(), 58);
+    }
+
+    proptest! {
+        #[test]
+        fn round_trip(input in grid(select(vec![Cell::Empty, Cell::East, Cell::South]), 1..20, 1..20)) {
+            check_round_trip(&input);
+        }
+    }
+}
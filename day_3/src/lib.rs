@@ -0,0 +1,181 @@
+use anyhow::{anyhow, Result};
+use problem::{Locate, Problem};
+
+pub type Entry = u16;
+
+#[derive(Debug)]
+pub struct Input {
+    pub width: usize,
+    pub entries: Vec<Entry>,
+}
+
+impl problem::Input for Input {
+    fn parse<R: std::io::BufRead>(reader: R) -> Result<Self> {
+        let mut width = 0;
+        let mut entries = Vec::new();
+        for (y, line) in reader.lines().enumerate() {
+            let line = line?;
+            if line.len() > Entry::BITS as usize {
+                return Err(anyhow!("Entry is longer than {} bits", Entry::BITS))
+                    .at_line(y, &line)?;
+            }
+            width = line.len();
+            let mut entry: Entry = 0;
+            for (x, c) in line.chars().enumerate() {
+                let b = match c {
+                    '0' => 0,
+                    '1' => 1,
+                    _ => return Err(anyhow!("Invalid char: '{}'", c)).at(y, x, &line)?,
+                };
+                entry = entry << 1 | b;
+            }
+            entries.push(entry);
+        }
+        Ok(Self { width, entries })
+    }
+}
+
+impl problem::Unparse for Input {
+    fn unparse<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        for entry in self.entries.iter() {
+            writeln!(writer, "{:01$b}", entry, self.width)?;
+        }
+        Ok(())
+    }
+}
+
+fn find_rating(input: &Input, value: Entry) -> Result<usize> {
+    let mut candidates = input.entries.clone();
+    let mut i = input.width;
+    while candidates.len() > 1 {
+        i = i
+            .checked_sub(1)
+            .ok_or_else(|| anyhow!("Duplicate entries left after checking every bit"))?;
+        let ones = candidates
+            .iter()
+            .map(|&c| (c >> i & 1) as usize)
+            .sum::<usize>();
+        let zeros = candidates.len() - ones;
+        if ones == 0 || zeros == 0 {
+            continue;
+        }
+        let filter = if ones >= zeros { value } else { 1 - value };
+        candidates.retain(|&c| c >> i & 1 == filter);
+    }
+    candidates
+        .first()
+        .map(|&c| c as usize)
+        .ok_or_else(|| anyhow!("No entries"))
+}
+
+pub struct Day3;
+
+impl Problem for Day3 {
+    const DAY: usize = 3;
+
+    type Input = Input;
+    type Params = ();
+    type Shared = ();
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn solve_part_one(
+        input: &Self::Input,
+        _shared: &Self::Shared,
+        _params: &Self::Params,
+    ) -> Result<Self::PartOne> {
+        if input.entries.is_empty() {
+            return Err(anyhow!("No entries"));
+        }
+        let mut gamma = 0;
+        for i in (0..input.width).rev() {
+            gamma <<= 1;
+            let ones = input
+                .entries
+                .iter()
+                .map(|&e| (e >> i & 1) as usize)
+                .sum::<usize>();
+            if ones >= input.entries.len() - ones {
+                gamma |= 1;
+            }
+        }
+        let epsilon = gamma ^ ((1 << input.width) - 1);
+        Ok(gamma * epsilon)
+    }
+
+    fn solve_part_two(
+        input: &Self::Input,
+        _shared: &Self::Shared,
+        _params: &Self::Params,
+    ) -> Result<Self::PartTwo> {
+        let oxygen = find_rating(input, 1)?;
+        let co2 = find_rating(input, 0)?;
+        Ok(oxygen * co2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Day3, Input};
+    use problem::{
+        testing::{check_round_trip, check_solvers},
+        Input as _, Problem,
+    };
+    use proptest::{collection::vec, prelude::*};
+
+    problem::example_test!(Day3, "example_1.txt", part_one = 198, part_two = 230);
+
+    // Once the remaining candidates all share a bit, filtering by the least common value
+    // would leave none, so that bit is skipped instead
+    #[test]
+    fn candidates_share_a_bit() {
+        let input = Input {
+            width: 3,
+            entries: vec![0b010, 0b011, 0b110, 0b111, 0b101],
+        };
+        assert_eq!(
+            Day3::solve_part_two(&input, &(), &()).unwrap(),
+            0b111 * 0b010
+        );
+    }
+
+    #[test]
+    fn empty() {
+        let input = Input {
+            width: 0,
+            entries: Vec::new(),
+        };
+        assert!(Day3::solve_part_one(&input, &(), &()).is_err());
+        assert!(Day3::solve_part_two(&input, &(), &()).is_err());
+    }
+
+    #[test]
+    fn duplicates() {
+        let input = Input {
+            width: 2,
+            entries: vec![2, 2],
+        };
+        assert!(Day3::solve_part_two(&input, &(), &()).is_err());
+    }
+
+    #[test]
+    fn entry_too_long() {
+        assert!(Input::parse("10101010101010101\n".as_bytes()).is_err());
+    }
+
+    // Includes empty inputs and duplicate entries, which the solvers must reject with errors
+    fn input() -> impl Strategy<Value = Input> {
+        (1..=12usize).prop_flat_map(|width| {
+            vec(0..1u16 << width, 0..1 << width.min(6))
+                .prop_map(move |entries| Input { width, entries })
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn round_trip(input in input()) {
+            check_round_trip(&input);
+            check_solvers::<Day3>(&input);
+        }
+    }
+}
@@ -0,0 +1,241 @@
+use anyhow::{anyhow, Result};
+use problem::{Problem, Sections, CSV};
+
+// The order of numbers that are never drawn, and the earliest win of boards that never win
+const NEVER: u8 = u8::MAX;
+
+#[derive(Debug, Default)]
+pub struct Board {
+    pub numbers: [u8; 25],
+}
+
+impl Board {
+    fn earliest_win(&self, order: &[u8]) -> u8 {
+        let mut earliest = NEVER;
+        for i in 0..5 {
+            let mut latest = 0;
+            for j in 0..5 {
+                latest = u8::max(latest, order[self.numbers[5 * i + j] as usize]);
+            }
+            earliest = u8::min(earliest, latest);
+        }
+        for j in 0..5 {
+            let mut latest = 0;
+            for i in 0..5 {
+                latest = u8::max(latest, order[self.numbers[5 * i + j] as usize]);
+            }
+            earliest = u8::min(earliest, latest);
+        }
+        earliest
+    }
+
+    fn unmarked_total(&self, order: &[u8], draw: u8) -> usize {
+        let mut unmarked = 0;
+        for i in self.numbers {
+            if order[i as usize] > draw {
+                unmarked += i as usize;
+            }
+        }
+        unmarked
+    }
+}
+
+#[derive(Debug)]
+pub struct Input {
+    pub numbers: Vec<u8>,
+    pub boards: Vec<Board>,
+}
+
+impl problem::Input for Board {
+    fn parse<R: std::io::BufRead>(reader: R) -> Result<Self> {
+        let mut board = Board::default();
+        let mut count = 0;
+        for line in reader.lines() {
+            for n in line?.split_whitespace() {
+                let slot = board
+                    .numbers
+                    .get_mut(count)
+                    .ok_or_else(|| anyhow!("Board has more than 25 numbers"))?;
+                *slot = n.parse()?;
+                count += 1;
+            }
+        }
+        if count < 25 {
+            return Err(anyhow!("Board has only {} numbers, expected 25", count));
+        }
+        Ok(board)
+    }
+}
+
+impl problem::Input for Input {
+    fn parse<R: std::io::BufRead>(reader: R) -> Result<Self> {
+        let (numbers, boards) = <(Vec<CSV<u8>>, Sections<Board>)>::parse(reader)?;
+        Ok(Self {
+            numbers: numbers.iter().flat_map(|l| l.values()).copied().collect(),
+            boards: boards.into_values(),
+        })
+    }
+}
+
+impl problem::Unparse for Board {
+    fn unparse<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        for row in self.numbers.chunks(5) {
+            let row = row.iter().map(|n| format!("{:2}", n)).collect::<Vec<_>>();
+            writeln!(writer, "{}", row.join(" "))?;
+        }
+        Ok(())
+    }
+}
+
+impl problem::Unparse for Input {
+    fn unparse<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        let numbers = self.numbers.iter().map(u8::to_string).collect::<Vec<_>>();
+        writeln!(writer, "{}", numbers.join(","))?;
+        for board in self.boards.iter() {
+            writeln!(writer)?;
+            board.unparse(writer)?;
+        }
+        Ok(())
+    }
+}
+
+pub struct Day4;
+
+impl Problem for Day4 {
+    const DAY: usize = 4;
+
+    type Input = Input;
+    type Params = ();
+    type Shared = Vec<u8>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn prepare(input: &Self::Input, _params: &Self::Params) -> Result<Self::Shared> {
+        if input.numbers.len() >= NEVER as usize {
+            return Err(anyhow!(
+                "Too many numbers drawn, expected fewer than {}",
+                NEVER
+            ));
+        }
+        let mut order = vec![NEVER; NEVER as usize + 1];
+        for (i, &n) in input.numbers.iter().enumerate() {
+            if order[n as usize] == NEVER {
+                order[n as usize] = i as u8;
+            }
+        }
+        Ok(order)
+    }
+
+    fn solve_part_one(
+        input: &Self::Input,
+        order: &Self::Shared,
+        _params: &Self::Params,
+    ) -> Result<Self::PartOne> {
+        let winner = input
+            .boards
+            .iter()
+            .min_by_key(|&b| b.earliest_win(order))
+            .ok_or_else(|| anyhow!("No boards to play"))?;
+        let draw = winner.earliest_win(order);
+        if draw == NEVER {
+            return Err(anyhow!("No board ever wins"));
+        }
+        let total = winner.unmarked_total(order, draw);
+        let last = input.numbers[draw as usize] as usize;
+        Ok(total * last)
+    }
+
+    fn solve_part_two(
+        input: &Self::Input,
+        order: &Self::Shared,
+        _params: &Self::Params,
+    ) -> Result<Self::PartTwo> {
+        let loser = input
+            .boards
+            .iter()
+            .max_by_key(|&b| b.earliest_win(order))
+            .ok_or_else(|| anyhow!("No boards to play"))?;
+        let draw = loser.earliest_win(order);
+        if draw == NEVER {
+            return Err(anyhow!("Some boards never win"));
+        }
+        let total = loser.unmarked_total(order, draw);
+        let last = input.numbers[draw as usize] as usize;
+        Ok(total * last)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Board, Day4, Input};
+    use problem::{
+        testing::{check_round_trip, check_solvers},
+        Problem,
+    };
+    use proptest::{collection::vec, prelude::*, sample::subsequence};
+
+    problem::example_test!(Day4, "example_1.txt", part_one = 4512, part_two = 1924);
+
+    fn board_from(first: u8) -> Board {
+        Board {
+            numbers: (first..first + 25).collect::<Vec<_>>().try_into().unwrap(),
+        }
+    }
+
+    #[test]
+    fn numbers_above_99() {
+        let input = Input {
+            numbers: vec![200, 201, 202, 203, 204],
+            boards: vec![board_from(200)],
+        };
+        let shared = Day4::prepare(&input, &()).unwrap();
+        let score = (205..225).sum::<usize>() * 204;
+        assert_eq!(Day4::solve_part_one(&input, &shared, &()).unwrap(), score);
+        assert_eq!(Day4::solve_part_two(&input, &shared, &()).unwrap(), score);
+    }
+
+    #[test]
+    fn board_never_wins() {
+        let input = Input {
+            numbers: vec![0, 1, 2, 3, 4, 250],
+            boards: vec![board_from(0), board_from(100)],
+        };
+        let shared = Day4::prepare(&input, &()).unwrap();
+        assert_eq!(
+            Day4::solve_part_one(&input, &shared, &()).unwrap(),
+            (5..25).sum::<usize>() * 4
+        );
+        assert!(Day4::solve_part_two(&input, &shared, &()).is_err());
+
+        let input = Input {
+            numbers: vec![0, 1, 2],
+            boards: vec![board_from(0)],
+        };
+        let shared = Day4::prepare(&input, &()).unwrap();
+        assert!(Day4::solve_part_one(&input, &shared, &()).is_err());
+    }
+
+    fn board() -> impl Strategy<Value = Board> {
+        subsequence((0..100).collect::<Vec<u8>>(), 25)
+            .prop_shuffle()
+            .prop_map(|numbers| Board {
+                numbers: numbers.try_into().unwrap(),
+            })
+    }
+
+    fn input() -> impl Strategy<Value = Input> {
+        (
+            Just((0..100).collect::<Vec<u8>>()).prop_shuffle(),
+            vec(board(), 1..10),
+        )
+            .prop_map(|(numbers, boards)| Input { numbers, boards })
+    }
+
+    proptest! {
+        #[test]
+        fn round_trip(input in input()) {
+            check_round_trip(&input);
+            check_solvers::<Day4>(&input);
+        }
+    }
+}
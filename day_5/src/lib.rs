@@ -0,0 +1,152 @@
+use ::core::str::FromStr;
+use anyhow::{anyhow, Error, Result};
+use problem::Problem;
+use std::{collections::HashMap, fmt};
+
+fn gcd(mut a: i32, mut b: i32) -> i32 {
+    while b != 0 {
+        let t = b;
+        b = a % b;
+        a = t;
+    }
+    a
+}
+
+#[derive(Debug, Hash, PartialEq, Eq)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl FromStr for Point {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut coords = s.split(',').map(|x| x.parse());
+        Ok(Self {
+            x: coords.next().ok_or(anyhow!("Missing x coordinate"))??,
+            y: coords.next().ok_or(anyhow!("Missing y coordinate"))??,
+        })
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+#[derive(Debug)]
+pub struct Line(pub Point, pub Point);
+
+impl Line {
+    pub fn is_straight(&self) -> bool {
+        self.0.x == self.1.x || self.0.y == self.1.y
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let x0 = self.0.x;
+        let y0 = self.0.y;
+        let dx = self.1.x - self.0.x;
+        let dy = self.1.y - self.0.y;
+        let n = gcd(dy, dx).abs();
+        let (sx, sy) = if n == 0 { (0, 0) } else { (dx / n, dy / n) };
+        (0..=n).map(move |i| Point {
+            x: x0 + sx * i,
+            y: y0 + sy * i,
+        })
+    }
+}
+
+impl FromStr for Line {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut points = s.split(" -> ").map(|x| x.parse());
+        Ok(Self(
+            points.next().ok_or(anyhow!("Missing start point"))??,
+            points.next().ok_or(anyhow!("Missing end point"))??,
+        ))
+    }
+}
+
+impl fmt::Display for Line {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} -> {}", self.0, self.1)
+    }
+}
+
+fn count_duplicate_points<'a>(lines: impl Iterator<Item = &'a Line>) -> usize {
+    let mut points = HashMap::new();
+    for line in lines {
+        for point in line.points() {
+            *points.entry(point).or_insert(0) += 1;
+        }
+    }
+    points.values().filter(|&x| *x > 1).count()
+}
+
+pub struct Day5;
+
+impl Problem for Day5 {
+    const DAY: usize = 5;
+
+    type Input = Vec<Line>;
+    type Params = ();
+    type Shared = ();
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn solve_part_one(
+        input: &Self::Input,
+        _shared: &Self::Shared,
+        _params: &Self::Params,
+    ) -> Result<Self::PartOne> {
+        Ok(count_duplicate_points(
+            input.iter().filter(|l| l.is_straight()),
+        ))
+    }
+
+    fn solve_part_two(
+        input: &Self::Input,
+        _shared: &Self::Shared,
+        _params: &Self::Params,
+    ) -> Result<Self::PartTwo> {
+        Ok(count_duplicate_points(input.iter()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Day5, Line, Point};
+    use problem::testing::{check_round_trip, check_solvers};
+    use proptest::{collection::vec, prelude::*};
+
+    problem::example_test!(Day5, "example_1.txt", part_one = 5, part_two = 12);
+
+    // A line from a point to itself has no direction to step in, but still covers that point
+    problem::example_test!(
+        single_point: Day5,
+        input = "1,1 -> 1,1\n0,1 -> 2,1\n3,3 -> 3,3\n",
+        part_one = 1,
+        part_two = 1
+    );
+
+    #[test]
+    fn single_point_line() {
+        let line = Line(Point { x: 3, y: 4 }, Point { x: 3, y: 4 });
+        assert_eq!(line.points().collect::<Vec<_>>(), [Point { x: 3, y: 4 }]);
+    }
+
+    fn point() -> impl Strategy<Value = Point> {
+        (0..1000i32, 0..1000i32).prop_map(|(x, y)| Point { x, y })
+    }
+
+    proptest! {
+        #[test]
+        fn round_trip(input in vec((point(), point()).prop_map(|(a, b)| Line(a, b)), 0..20)) {
+            check_round_trip(&input);
+            check_solvers::<Day5>(&input);
+        }
+    }
+}
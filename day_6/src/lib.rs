@@ -0,0 +1,287 @@
+use anyhow::{anyhow, Result};
+use nalgebra::{
+    base::{SMatrix, SVector},
+    ArrayStorage, Complex,
+};
+use problem::{Problem, Solver, Unsupported, CSV};
+
+// Rounding error in the closed form reaches half a fish at around 2^45 fish, well before f64
+// runs out of integer precision at 2^53, so larger answers are left to the simulation
+const EXACT: f64 = (1u64 << 43) as f64;
+
+fn count_timers(initial: &[usize]) -> Result<[u64; 9]> {
+    let mut count = [0; 9];
+    for &i in initial.iter() {
+        *count
+            .get_mut(i)
+            .ok_or_else(|| anyhow!("Invalid timer {}, expected at most 8", i))? += 1;
+    }
+    Ok(count)
+}
+
+pub fn simulate(initial: &[usize], duration: usize) -> Result<u64> {
+    let overflow = |day| anyhow!("Number of lanternfish overflowed after {} days", day);
+
+    let mut count = count_timers(initial)?;
+
+    for day in 0..duration {
+        problem::check_cancelled()?;
+        let zeros = count[0];
+        for i in 0..8 {
+            count[i] = count[i + 1];
+        }
+        count[8] = zeros;
+        count[6] = count[6]
+            .checked_add(zeros)
+            .ok_or_else(|| overflow(day + 1))?;
+    }
+
+    count
+        .iter()
+        .try_fold(0u64, |total, &n| total.checked_add(n))
+        .ok_or_else(|| overflow(duration))
+}
+
+const ZERO: Complex<f64> = Complex { re: 0.0, im: 0.0 };
+const ONE: Complex<f64> = Complex { re: 1.0, im: 0.0 };
+const TWO: Complex<f64> = Complex { re: 2.0, im: 0.0 };
+
+// Roots of the characteristic polynomial x^9 - x^2 - 1
+// x≈-0.996130622055441 - 0.417311836335793 i
+// x≈-0.996130622055441 + 0.417311836335793 i
+// x≈-0.379213980654811 - 0.892877546086168 i
+// x≈-0.379213980654811 + 0.892877546086168 i
+// x≈0.095754469006120 - 0.870198718672104 i
+// x≈0.095754469006120 + 0.870198718672104 i
+// x≈0.734077898463753 - 0.742065121962188 i
+// x≈0.734077898463753 + 0.742065121962188 i
+// x≈1.0910244704807567604
+const ROOTS: [Complex<f64>; 9] = [
+    Complex {
+        re: -0.996130622055441,
+        im: 0.417311836335793,
+    },
+    Complex {
+        re: -0.996130622055441,
+        im: -0.417311836335793,
+    },
+    Complex {
+        re: -0.379213980654811,
+        im: 0.892877546086168,
+    },
+    Complex {
+        re: -0.379213980654811,
+        im: -0.892877546086168,
+    },
+    Complex {
+        re: 0.095754469006120,
+        im: 0.870198718672104,
+    },
+    Complex {
+        re: 0.095754469006120,
+        im: -0.870198718672104,
+    },
+    Complex {
+        re: 0.734077898463753,
+        im: 0.742065121962188,
+    },
+    Complex {
+        re: 0.734077898463753,
+        im: -0.742065121962188,
+    },
+    Complex {
+        re: 1.0910244704807568,
+        im: 0.0,
+    },
+];
+
+// Used to calculate the values of COEFFS
+pub fn calc_coeffs() -> SVector<Complex<f64>, 9> {
+    let m = SMatrix::<Complex<f64>, 9, 9>::from_fn(|r, c| ROOTS[c].powu(r as u32));
+    let b = SVector::<Complex<f64>, 9>::from([ONE, ZERO, ZERO, ZERO, ZERO, ZERO, ZERO, ONE, ZERO]);
+    let decomp = m.lu();
+    decomp.solve(&b).expect("Linear resolution failed.")
+}
+
+pub fn calculate(initial: &[usize], duration: usize) -> Result<u64> {
+    #[inline]
+    fn f(roots: &[Complex<f64>; 9], coeffs: &SVector<Complex<f64>, 9>, day: i32) -> Complex<f64> {
+        let mut result = ZERO;
+        for i in 0..9 {
+            result += coeffs[i] * roots[i].powi(day);
+        }
+        result
+    }
+
+    #[inline]
+    fn g(roots: &[Complex<f64>; 9], coeffs: &SVector<Complex<f64>, 9>, day: i32) -> Complex<f64> {
+        TWO * f(roots, coeffs, day - 1)
+            + TWO * f(roots, coeffs, day - 2)
+            + TWO * f(roots, coeffs, day - 3)
+            + TWO * f(roots, coeffs, day - 4)
+            + TWO * f(roots, coeffs, day - 5)
+            + TWO * f(roots, coeffs, day - 6)
+            + TWO * f(roots, coeffs, day - 7)
+            + f(roots, coeffs, day - 8)
+            + f(roots, coeffs, day - 9)
+    }
+
+    let count = count_timers(initial)?;
+    let duration = i32::try_from(duration)
+        .map_err(|_| Unsupported(format!("{} days is too long to calculate", duration)))?;
+
+    const COEFFS: SVector<Complex<f64>, 9> = SVector::from_array_storage(ArrayStorage([[
+        Complex {
+            re: 0.1260768114963526,
+            im: -0.00663123683235536,
+        },
+        Complex {
+            re: 0.12607681149635266,
+            im: 0.006631236832355397,
+        },
+        Complex {
+            re: 0.11279107390309294,
+            im: -0.032200935222245805,
+        },
+        Complex {
+            re: 0.11279107390309287,
+            im: 0.032200935222245756,
+        },
+        Complex {
+            re: 0.07359404243842448,
+            im: 0.021469402001632148,
+        },
+        Complex {
+            re: 0.07359404243842455,
+            im: -0.02146940200163227,
+        },
+        Complex {
+            re: 0.12435173095411725,
+            im: 0.015825935818472307,
+        },
+        Complex {
+            re: 0.12435173095411729,
+            im: -0.015825935818472303,
+        },
+        Complex {
+            re: 0.12637268241602523,
+            im: -0.00000000000000000021747809549865233,
+        },
+    ]]));
+
+    let result = count
+        .iter()
+        .enumerate()
+        .map(|(i, n)| g(&ROOTS, &COEFFS, duration - i as i32) * *n as f64)
+        .sum::<Complex<f64>>();
+    if !result.re.is_finite() || result.re.abs() > EXACT {
+        return Err(Unsupported(format!(
+            "{} lanternfish is too many to calculate exactly",
+            result.re
+        ))
+        .into());
+    }
+    Ok(result.re.round() as u64)
+}
+
+pub struct Day6;
+
+problem::params! {
+    pub struct Params {
+        part_one_days: usize = 80,
+        part_two_days: usize = 256,
+    }
+}
+
+impl Problem for Day6 {
+    const DAY: usize = 6;
+
+    type Input = CSV<usize>;
+    type Params = Params;
+    type Shared = ();
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn solve_part_one(
+        input: &Self::Input,
+        _shared: &Self::Shared,
+        params: &Self::Params,
+    ) -> Result<Self::PartOne> {
+        simulate(input.values(), params.part_one_days)
+    }
+
+    fn solve_part_two(
+        input: &Self::Input,
+        _shared: &Self::Shared,
+        params: &Self::Params,
+    ) -> Result<Self::PartTwo> {
+        simulate(input.values(), params.part_two_days)
+    }
+
+    fn part_one_alternatives() -> Vec<(&'static str, Solver<Self, Self::PartOne>)> {
+        vec![("calculate", |input, _, params| {
+            calculate(input.values(), params.part_one_days)
+        })]
+    }
+
+    fn part_two_alternatives() -> Vec<(&'static str, Solver<Self, Self::PartTwo>)> {
+        vec![("calculate", |input, _, params| {
+            calculate(input.values(), params.part_two_days)
+        })]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{calculate, simulate, Day6};
+    use problem::{
+        testing::{check_round_trip, check_solvers},
+        Unsupported, CSV,
+    };
+    use proptest::{collection::vec, prelude::*};
+
+    problem::example_test!(
+        Day6,
+        "example_1.txt",
+        part_one = 5934,
+        part_two = 26984457539u64
+    );
+
+    #[test]
+    fn calculate_matches_simulate() {
+        let initial = [3, 4, 3, 1, 2];
+        for duration in 0..=256 {
+            assert_eq!(
+                calculate(&initial, duration).unwrap(),
+                simulate(&initial, duration).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn simulate_overflow() {
+        let error = simulate(&[3, 4, 3, 1, 2], 1000).unwrap_err();
+        assert!(error.to_string().contains("overflowed"));
+    }
+
+    #[test]
+    fn calculate_beyond_f64_precision() {
+        let error = calculate(&[3, 4, 3, 1, 2], 400).unwrap_err();
+        assert!(error.is::<Unsupported>());
+        assert!(simulate(&[3, 4, 3, 1, 2], 400).is_ok());
+    }
+
+    #[test]
+    fn invalid_timer() {
+        assert!(simulate(&[3, 9], 18).is_err());
+        assert!(calculate(&[3, 9], 18).is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn round_trip(input in vec(0..=8usize, 1..300).prop_map(CSV::from)) {
+            check_round_trip(&input);
+            check_solvers::<Day6>(&input);
+        }
+    }
+}
@@ -0,0 +1,93 @@
+use anyhow::{anyhow, Result};
+use problem::{Problem, CSV};
+
+pub struct Day7;
+
+impl Problem for Day7 {
+    const DAY: usize = 7;
+
+    type Input = CSV<i32>;
+    type Params = ();
+    type Shared = ();
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn solve_part_one(
+        input: &Self::Input,
+        _shared: &Self::Shared,
+        _params: &Self::Params,
+    ) -> Result<Self::PartOne> {
+        if input.values().is_empty() {
+            return Err(anyhow!("No crab positions"));
+        }
+        let mut sorted = input.values().to_owned();
+        sorted.sort_unstable();
+        let median = sorted[sorted.len() / 2];
+        Ok(sorted.iter().map(|&x| (median - x).abs()).sum::<i32>())
+    }
+
+    fn solve_part_two(
+        input: &Self::Input,
+        _shared: &Self::Shared,
+        _params: &Self::Params,
+    ) -> Result<Self::PartTwo> {
+        let mut current = *input
+            .values()
+            .iter()
+            .min()
+            .ok_or_else(|| anyhow!("No crab positions"))?;
+        let max = *input
+            .values()
+            .iter()
+            .max()
+            .ok_or_else(|| anyhow!("No crab positions"))?;
+        let mut cost = input
+            .values()
+            .iter()
+            .map(|&x| {
+                let dx = (x - current).abs();
+                dx * (dx + 1) / 2
+            })
+            .sum();
+        while current < max {
+            let next = current + 1;
+            let dc: i32 = input
+                .values()
+                .iter()
+                .map(|&x| {
+                    if current >= x {
+                        current - x + 1
+                    } else {
+                        current - x
+                    }
+                })
+                .sum();
+            if dc > 0 {
+                break;
+            }
+            cost += dc;
+            current = next;
+        }
+        Ok(cost)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day7;
+    use problem::{
+        testing::{check_round_trip, check_solvers},
+        CSV,
+    };
+    use proptest::{collection::vec, prelude::*};
+
+    problem::example_test!(Day7, "example_1.txt", part_one = 37, part_two = 168);
+
+    proptest! {
+        #[test]
+        fn round_trip(input in vec(0..2000i32, 0..100).prop_map(CSV::from)) {
+            check_round_trip(&input);
+            check_solvers::<Day7>(&input);
+        }
+    }
+}
@@ -0,0 +1,240 @@
+use anyhow::{anyhow, Error, Result};
+use problem::Problem;
+use std::{fmt, str::FromStr};
+
+const A: u8 = 0b0000001;
+const B: u8 = 0b0000010;
+const C: u8 = 0b0000100;
+const D: u8 = 0b0001000;
+const E: u8 = 0b0010000;
+const F: u8 = 0b0100000;
+const G: u8 = 0b1000000;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Digit(pub u8);
+
+impl Digit {
+    fn set(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    fn matches(self, other: Digit) -> usize {
+        (self.0 & other.0).count_ones() as usize
+    }
+}
+
+impl FromStr for Digit {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut result = 0;
+        for c in s.chars() {
+            result |= match c {
+                'a' => A,
+                'b' => B,
+                'c' => C,
+                'd' => D,
+                'e' => E,
+                'f' => F,
+                'g' => G,
+                _ => return Err(anyhow!("Invalid display segment: {}", c)),
+            }
+        }
+        Ok(Digit(result))
+    }
+}
+
+impl fmt::Display for Digit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (segment, c) in [A, B, C, D, E, F, G].into_iter().zip('a'..='g') {
+            if self.0 & segment != 0 {
+                write!(f, "{}", c)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub struct Display {
+    pub combinations: [Digit; 10],
+    pub digits: [Digit; 4],
+}
+
+const REAL_DIGITS: [Digit; 10] = [
+    Digit(A | B | C | E | F | G),
+    Digit(C | F),
+    Digit(A | C | D | E | G),
+    Digit(A | C | D | F | G),
+    Digit(B | C | D | F),
+    Digit(A | B | D | F | G),
+    Digit(A | B | D | E | F | G),
+    Digit(A | C | F),
+    Digit(A | B | C | D | E | F | G),
+    Digit(A | B | C | D | F | G),
+];
+
+impl Display {
+    pub fn solve(&self) -> Result<usize> {
+        let mut candidates = [
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+        ];
+        for (i, &d) in self.combinations.iter().enumerate() {
+            let num_set = d.set();
+            for (j, &n) in REAL_DIGITS.iter().enumerate() {
+                if n.set() == num_set {
+                    candidates[i].push(j);
+                }
+            }
+        }
+
+        while candidates.iter().any(|c| c.len() != 1) {
+            problem::check_cancelled()?;
+            for i in 0..10 {
+                if candidates[i].len() == 1 {
+                    let disc_scrambled = self.combinations[i];
+                    let disc_real = REAL_DIGITS[candidates[i][0]];
+                    for (j, candidates) in candidates.iter_mut().enumerate() {
+                        let unknown_scrambled = self.combinations[j];
+                        let matches = unknown_scrambled.matches(disc_scrambled);
+                        candidates.retain(|c| REAL_DIGITS[*c].matches(disc_real) == matches);
+                    }
+                }
+            }
+        }
+
+        let mut result = 0;
+        for i in 0..4 {
+            let index = (0..10)
+                .find(|&n| self.combinations[n] == self.digits[i])
+                .ok_or_else(|| anyhow!("Digit does not match any combination"))?;
+            result = result * 10 + candidates[index][0];
+        }
+
+        Ok(result)
+    }
+}
+
+impl FromStr for Display {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut combinations = [Digit::default(); 10];
+        let mut digits = [Digit::default(); 4];
+
+        let mut pieces = s.split(" | ");
+        for (i, combination) in pieces
+            .next()
+            .ok_or(anyhow!("Missing combinations"))?
+            .split(' ')
+            .enumerate()
+        {
+            combinations[i] = combination.parse()?;
+        }
+        for (i, digit) in pieces
+            .next()
+            .ok_or(anyhow!("Missing digits"))?
+            .split(' ')
+            .enumerate()
+        {
+            digits[i] = digit.parse()?;
+        }
+        Ok(Display {
+            combinations,
+            digits,
+        })
+    }
+}
+
+impl fmt::Display for Display {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let combinations = self.combinations.map(|d| d.to_string()).join(" ");
+        let digits = self.digits.map(|d| d.to_string()).join(" ");
+        write!(f, "{} | {}", combinations, digits)
+    }
+}
+
+pub struct Day8;
+
+impl Problem for Day8 {
+    const DAY: usize = 8;
+
+    type Input = Vec<Display>;
+    type Params = ();
+    type Shared = ();
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn solve_part_one(
+        input: &Self::Input,
+        _shared: &Self::Shared,
+        _params: &Self::Params,
+    ) -> Result<Self::PartOne> {
+        Ok(input
+            .iter()
+            .map(|d| {
+                d.digits
+                    .iter()
+                    .filter(|d| d.set() == 2 || d.set() == 3 || d.set() == 4 || d.set() == 7)
+                    .count()
+            })
+            .sum())
+    }
+
+    fn solve_part_two(
+        input: &Self::Input,
+        _shared: &Self::Shared,
+        _params: &Self::Params,
+    ) -> Result<Self::PartTwo> {
+        input.iter().map(|d| d.solve()).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Day8, Digit, Display, REAL_DIGITS};
+    use problem::testing::{check_round_trip, check_solvers};
+    use proptest::{collection::vec, prelude::*, sample::select};
+    use std::array;
+
+    problem::example_test!(Day8, "example_1.txt", part_one = 26, part_two = 61229);
+
+    fn display() -> impl Strategy<Value = Display> {
+        (
+            Just((0..7).collect::<Vec<u8>>()).prop_shuffle(),
+            Just((0..10).collect::<Vec<usize>>()).prop_shuffle(),
+            vec(select((0..10).collect::<Vec<usize>>()), 4),
+        )
+            .prop_map(|(wiring, order, digits)| {
+                let scramble = |d: Digit| {
+                    Digit(
+                        (0..7)
+                            .filter(|&i| d.0 & 1 << i != 0)
+                            .map(|i| 1 << wiring[i])
+                            .sum(),
+                    )
+                };
+                Display {
+                    combinations: array::from_fn(|i| scramble(REAL_DIGITS[order[i]])),
+                    digits: array::from_fn(|i| scramble(REAL_DIGITS[digits[i]])),
+                }
+            })
+    }
+
+    proptest! {
+        #[test]
+        fn round_trip(input in vec(display(), 0..20)) {
+            check_round_trip(&input);
+            check_solvers::<Day8>(&input);
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use ::anyhow::{anyhow, Result};
+use problem::{Grid, Problem};
+
+pub struct Day9;
+
+fn is_low_point(input: &Grid<u8>, x: usize, y: usize) -> bool {
+    input
+        .neighbors4(x, y)
+        .all(|(nx, ny)| input[(nx, ny)] > input[(x, y)])
+}
+
+impl Problem for Day9 {
+    const DAY: usize = 9;
+
+    type Input = Grid<u8>;
+    type Params = ();
+    type Shared = ();
+    type PartOne = i32;
+    type PartTwo = usize;
+
+    fn solve_part_one(
+        input: &Self::Input,
+        _shared: &Self::Shared,
+        _params: &Self::Params,
+    ) -> Result<Self::PartOne> {
+        Ok(input
+            .points()
+            .filter(|&(x, y)| is_low_point(input, x, y))
+            .map(|(x, y)| input[(x, y)] as i32 + 1)
+            .sum())
+    }
+
+    fn solve_part_two(
+        input: &Self::Input,
+        _shared: &Self::Shared,
+        _params: &Self::Params,
+    ) -> Result<Self::PartTwo> {
+        let mut basins = Grid::new(input.width(), input.height(), usize::MAX);
+        let mut counts = Vec::new();
+        for (i, (x, y)) in input
+            .points()
+            .filter(|&(x, y)| is_low_point(input, x, y))
+            .enumerate()
+        {
+            basins[(x, y)] = i;
+            counts.push(1);
+        }
+
+        let mut finished = false;
+        while !finished {
+            finished = true;
+            for (x, y) in input.points() {
+                let basin = basins[(x, y)];
+                if basin != usize::MAX {
+                    for (nx, ny) in input.neighbors4(x, y).filter(|&n| input[n] != 9) {
+                        if basins[(nx, ny)] == usize::MAX {
+                            basins[(nx, ny)] = basin;
+                            finished = false;
+                            counts[basin] += 1;
+                        }
+                    }
+                }
+            }
+        }
+
+        if counts.len() < 3 {
+            return Err(anyhow!("Found fewer than three basins"));
+        }
+        counts.sort_unstable();
+        Ok(counts[counts.len() - 3..].iter().product())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day9;
+    use problem::testing::{check_round_trip, check_solvers, grid};
+    use proptest::prelude::*;
+
+    problem::example_test!(Day9, "example_1.txt", part_one = 15, part_two = 1134);
+
+    proptest! {
+        #[test]
+        fn round_trip(input in grid(0..=9u8, 1..20, 1..20)) {
+            check_round_trip(&input);
+            check_solvers::<Day9>(&input);
+        }
+    }
+}
@@ -0,0 +1,98 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use days::*;
+use problem::{example_paths, input_path, Input, Problem};
+use std::{fs, path::PathBuf};
+
+// Benchmarks against the puzzle input when there is one, falling back to the first example
+fn find_input(day: usize) -> Option<PathBuf> {
+    let input = input_path(day).ok()?;
+    if input.exists() {
+        return Some(input);
+    }
+    example_paths(day).ok()?.into_iter().next()
+}
+
+fn bench<P: Problem>(c: &mut Criterion) {
+    let path = match find_input(P::DAY) {
+        Some(path) => path,
+        None => {
+            eprintln!("Skipping day {}: no input or examples found", P::DAY);
+            return;
+        }
+    };
+    let bytes = fs::read(&path).expect("Failed to read input");
+    let params = P::Params::default();
+    let input = P::Input::parse(bytes.as_slice()).expect("Failed to parse input");
+    let shared = P::prepare(&input, &params).expect("Failed to prepare input");
+
+    let mut group = c.benchmark_group(format!("day_{}", P::DAY));
+    group.bench_function("parse", |b| {
+        b.iter(|| P::Input::parse(black_box(bytes.as_slice())))
+    });
+    group.bench_function("part_one", |b| {
+        b.iter(|| P::solve_part_one(black_box(&input), &shared, &params))
+    });
+    for (name, solver) in P::part_one_alternatives() {
+        group.bench_function(format!("part_one/{}", name), |b| {
+            b.iter(|| solver(black_box(&input), &shared, &params))
+        });
+    }
+    group.bench_function("part_two", |b| {
+        b.iter(|| P::solve_part_two(black_box(&input), &shared, &params))
+    });
+    for (name, solver) in P::part_two_alternatives() {
+        group.bench_function(format!("part_two/{}", name), |b| {
+            b.iter(|| solver(black_box(&input), &shared, &params))
+        });
+    }
+    group.finish();
+}
+
+fn lanternfish(c: &mut Criterion) {
+    let initial = [3, 4, 3, 1, 2];
+    let mut group = c.benchmark_group("day_6/lanternfish");
+    for duration in [18, 80, 256] {
+        group.bench_with_input(
+            BenchmarkId::new("simulate", duration),
+            &duration,
+            |b, &duration| b.iter(|| day_6::simulate(black_box(&initial), duration)),
+        );
+        group.bench_with_input(
+            BenchmarkId::new("calculate", duration),
+            &duration,
+            |b, &duration| b.iter(|| day_6::calculate(black_box(&initial), duration)),
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench::<Day1>,
+    bench::<Day2>,
+    bench::<Day3>,
+    bench::<Day4>,
+    bench::<Day5>,
+    bench::<Day6>,
+    bench::<Day7>,
+    bench::<Day8>,
+    bench::<Day9>,
+    bench::<Day10>,
+    bench::<Day11>,
+    bench::<Day12>,
+    bench::<Day13>,
+    bench::<Day14>,
+    bench::<Day15>,
+    bench::<Day16>,
+    bench::<Day17>,
+    bench::<Day18>,
+    bench::<Day19>,
+    bench::<Day20>,
+    bench::<Day21>,
+    bench::<Day22>,
+    bench::<Day23>,
+    bench::<Day24>,
+    bench::<Day25>,
+    lanternfish,
+);
+criterion_main!(benches);
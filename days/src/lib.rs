@@ -0,0 +1,58 @@
+//! Re-exports every day's solution by number
+
+pub use day_1::{self, Day1};
+pub use day_10::{self, Day10};
+pub use day_11::{self, Day11};
+pub use day_12::{self, Day12};
+pub use day_13::{self, Day13};
+pub use day_14::{self, Day14};
+pub use day_15::{self, Day15};
+pub use day_16::{self, Day16};
+pub use day_17::{self, Day17};
+pub use day_18::{self, Day18};
+pub use day_19::{self, Day19};
+pub use day_2::{self, Day2};
+pub use day_20::{self, Day20};
+pub use day_21::{self, Day21};
+pub use day_22::{self, Day22};
+pub use day_23::{self, Day23};
+pub use day_24::{self, Day24};
+pub use day_25::{self, Day25};
+pub use day_3::{self, Day3};
+pub use day_4::{self, Day4};
+pub use day_5::{self, Day5};
+pub use day_6::{self, Day6};
+pub use day_7::{self, Day7};
+pub use day_8::{self, Day8};
+pub use day_9::{self, Day9};
+
+// Every day the runner knows about, in order
+pub fn all() -> Vec<problem::Day> {
+    vec![
+        problem::Day::of::<day_1::Day1>(),
+        problem::Day::of::<day_2::Day2>(),
+        problem::Day::of::<day_3::Day3>(),
+        problem::Day::of::<day_4::Day4>(),
+        problem::Day::of::<day_5::Day5>(),
+        problem::Day::of::<day_6::Day6>(),
+        problem::Day::of::<day_7::Day7>(),
+        problem::Day::of::<day_8::Day8>(),
+        problem::Day::of::<day_9::Day9>(),
+        problem::Day::of::<day_10::Day10>(),
+        problem::Day::visualized::<day_11::Day11>(),
+        problem::Day::of::<day_12::Day12>(),
+        problem::Day::visualized::<day_13::Day13>(),
+        problem::Day::of::<day_14::Day14>(),
+        problem::Day::of::<day_15::Day15>(),
+        problem::Day::of::<day_16::Day16>(),
+        problem::Day::of::<day_17::Day17>(),
+        problem::Day::of::<day_18::Day18>(),
+        problem::Day::of::<day_19::Day19>(),
+        problem::Day::visualized::<day_20::Day20>(),
+        problem::Day::of::<day_21::Day21>(),
+        problem::Day::of::<day_22::Day22>(),
+        problem::Day::of::<day_23::Day23>(),
+        problem::Day::of::<day_24::Day24>(),
+        problem::Day::visualized::<day_25::Day25>(),
+    ]
+}
@@ -0,0 +1,122 @@
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Allocations {
+    pub count: usize,
+    pub bytes: usize,
+    pub peak: usize,
+}
+
+#[cfg(feature = "count-alloc")]
+mod counting {
+    use super::Allocations;
+    use std::{
+        alloc::{GlobalAlloc, Layout, System},
+        cell::Cell,
+    };
+
+    #[derive(Clone, Copy)]
+    struct Counters {
+        count: usize,
+        bytes: usize,
+        live: isize,
+        peak: isize,
+    }
+
+    // Counters are per thread so that concurrent phases in --parallel mode don't
+    // see each other's allocations
+    thread_local! {
+        static COUNTERS: Cell<Counters> = const {
+            Cell::new(Counters {
+                count: 0,
+                bytes: 0,
+                live: 0,
+                peak: 0,
+            })
+        };
+    }
+
+    fn update(f: impl FnOnce(&mut Counters)) {
+        let _ = COUNTERS.try_with(|counters| {
+            let mut value = counters.get();
+            f(&mut value);
+            counters.set(value);
+        });
+    }
+
+    fn record(allocated: usize, freed: usize) {
+        update(|c| {
+            if allocated != 0 {
+                c.count += 1;
+                c.bytes += allocated;
+            }
+            c.live += allocated as isize - freed as isize;
+            c.peak = c.peak.max(c.live);
+        });
+    }
+
+    struct CountingAlloc;
+
+    unsafe impl GlobalAlloc for CountingAlloc {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc(layout);
+            if !ptr.is_null() {
+                record(layout.size(), 0);
+            }
+            ptr
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc_zeroed(layout);
+            if !ptr.is_null() {
+                record(layout.size(), 0);
+            }
+            ptr
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout);
+            record(0, layout.size());
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            let new_ptr = System.realloc(ptr, layout, new_size);
+            if !new_ptr.is_null() {
+                record(new_size, layout.size());
+            }
+            new_ptr
+        }
+    }
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAlloc = CountingAlloc;
+
+    pub fn measure<F: FnOnce() -> T, T>(f: F) -> (T, Option<Allocations>) {
+        let mut start = None;
+        update(|c| {
+            c.peak = c.live;
+            start = Some(*c);
+        });
+        let result = f();
+        let start = match start {
+            Some(start) => start,
+            None => return (result, None),
+        };
+        let end = COUNTERS.with(Cell::get);
+
+        (
+            result,
+            Some(Allocations {
+                count: end.count - start.count,
+                bytes: end.bytes - start.bytes,
+                peak: (end.peak - start.live).max(0) as usize,
+            }),
+        )
+    }
+}
+
+#[cfg(feature = "count-alloc")]
+pub use counting::measure;
+
+#[cfg(not(feature = "count-alloc"))]
+pub fn measure<F: FnOnce() -> T, T>(f: F) -> (T, Option<Allocations>) {
+    (f(), None)
+}
@@ -0,0 +1,218 @@
+use anyhow::{anyhow, Context, Error, Result};
+use std::{
+    fmt::{self, Display},
+    fs,
+    io::ErrorKind,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+const PART_ONE: &str = "Part one:";
+const PART_TWO: &str = "Part two:";
+
+pub enum Status {
+    Pass,
+    Fail { expected: String },
+    Timeout,
+    Unknown,
+}
+
+impl Status {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Status::Pass => "PASS",
+            Status::Fail { .. } => "FAIL",
+            Status::Timeout => "TIMEOUT",
+            Status::Unknown => "UNKNOWN",
+        }
+    }
+
+    pub fn is_fail(&self) -> bool {
+        matches!(self, Status::Fail { .. })
+    }
+
+    pub fn is_timeout(&self) -> bool {
+        matches!(self, Status::Timeout)
+    }
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Fail { expected } => write!(f, "{} (expected {})", self.name(), expected),
+            _ => write!(f, "{}", self.name()),
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct Answers {
+    pub part_one: Option<String>,
+    pub part_two: Option<String>,
+}
+
+fn check(expected: &Option<String>, result: &str) -> Status {
+    match expected {
+        None => Status::Unknown,
+        Some(expected) if expected == result.trim() => Status::Pass,
+        Some(expected) => Status::Fail {
+            expected: expected.clone(),
+        },
+    }
+}
+
+impl Answers {
+    pub fn path(input: &Path) -> PathBuf {
+        input.with_extension("answers")
+    }
+
+    pub fn load(input: &Path) -> Result<Self> {
+        let path = Self::path(input);
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => return Err(e).with_context(|| format!("Failed to read {}", path.display())),
+        };
+        contents
+            .parse()
+            .with_context(|| format!("Failed to parse {}", path.display()))
+    }
+
+    pub fn save(&self, input: &Path) -> Result<()> {
+        let path = Self::path(input);
+        fs::write(&path, self.to_string())
+            .with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    pub fn check_part_one(&self, result: &str) -> Status {
+        check(&self.part_one, result)
+    }
+
+    pub fn check_part_two(&self, result: &str) -> Status {
+        check(&self.part_two, result)
+    }
+}
+
+impl FromStr for Answers {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut part_one = None;
+        let mut part_two = None;
+        let mut current: Option<&mut Option<String>> = None;
+        for (line_number, line) in s.lines().enumerate() {
+            if line == PART_ONE {
+                current = Some(&mut part_one);
+            } else if line == PART_TWO {
+                current = Some(&mut part_two);
+            } else {
+                let answer = current.as_mut().ok_or_else(|| {
+                    anyhow!(
+                        "Expected '{}' or '{}' on line {}",
+                        PART_ONE,
+                        PART_TWO,
+                        line_number + 1
+                    )
+                })?;
+                let answer = answer.get_or_insert_with(String::new);
+                if !answer.is_empty() {
+                    answer.push('\n');
+                }
+                answer.push_str(line);
+            }
+        }
+
+        let trim = |answer: Option<String>| {
+            answer
+                .map(|a| a.trim().to_string())
+                .filter(|a| !a.is_empty())
+        };
+        Ok(Self {
+            part_one: trim(part_one),
+            part_two: trim(part_two),
+        })
+    }
+}
+
+impl Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(answer) = &self.part_one {
+            writeln!(f, "{}", PART_ONE)?;
+            writeln!(f, "{}", answer)?;
+        }
+        if let Some(answer) = &self.part_two {
+            writeln!(f, "{}", PART_TWO)?;
+            writeln!(f, "{}", answer)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Answers, Status};
+    use std::{env, fs, process};
+
+    #[test]
+    fn parse() {
+        let answers = "Part one:\n42\nPart two:\n#..#\n.##.\n\n"
+            .parse::<Answers>()
+            .unwrap();
+        assert_eq!(answers.part_one.as_deref(), Some("42"));
+        assert_eq!(answers.part_two.as_deref(), Some("#..#\n.##."));
+        assert!(matches!(answers.check_part_one("42\n"), Status::Pass));
+        assert!(answers.check_part_two("#..#\n....").is_fail());
+    }
+
+    #[test]
+    fn parse_missing_part() {
+        let answers = "Part two:\n7\n".parse::<Answers>().unwrap();
+        assert_eq!(answers.part_one, None);
+        assert_eq!(answers.part_two.as_deref(), Some("7"));
+        assert_eq!(answers.check_part_one("1").name(), "UNKNOWN");
+
+        let answers = "Part one:\n\nPart two:\n7\n".parse::<Answers>().unwrap();
+        assert_eq!(answers.part_one, None);
+    }
+
+    #[test]
+    fn parse_answer_without_part() {
+        let error = "42\nPart one:\n42\n".parse::<Answers>().err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "Expected 'Part one:' or 'Part two:' on line 1"
+        );
+    }
+
+    #[test]
+    fn write() {
+        let answers = Answers {
+            part_one: None,
+            part_two: Some("#.\n.#".to_string()),
+        };
+        let text = answers.to_string();
+        assert_eq!(text, "Part two:\n#.\n.#\n");
+        let parsed = text.parse::<Answers>().unwrap();
+        assert_eq!(parsed.part_one, None);
+        assert_eq!(parsed.part_two, answers.part_two);
+    }
+
+    #[test]
+    fn save_and_load() {
+        let directory = env::temp_dir().join(format!("answers_test_{}", process::id()));
+        fs::create_dir_all(&directory).unwrap();
+        let input = directory.join("input.txt");
+        assert_eq!(Answers::path(&input), directory.join("input.answers"));
+        assert!(Answers::load(&input).unwrap().part_one.is_none());
+
+        let answers = Answers {
+            part_one: Some("1".to_string()),
+            part_two: Some("2".to_string()),
+        };
+        answers.save(&input).unwrap();
+        let loaded = Answers::load(&input).unwrap();
+        fs::remove_dir_all(&directory).unwrap();
+        assert_eq!(loaded.part_one.as_deref(), Some("1"));
+        assert_eq!(loaded.part_two.as_deref(), Some("2"));
+    }
+}
@@ -0,0 +1,76 @@
+use anyhow::Result;
+use std::{
+    cell::RefCell,
+    error::Error,
+    fmt::{self, Display},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+#[derive(Debug)]
+pub struct Cancelled;
+
+impl Display for Cancelled {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Solver was cancelled")
+    }
+}
+
+impl Error for Cancelled {}
+
+#[derive(Clone, Copy, Debug)]
+pub struct TimedOut(pub Duration);
+
+impl Display for TimedOut {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Timed out after {} seconds", self.0.as_secs_f64())
+    }
+}
+
+impl Error for TimedOut {}
+
+thread_local! {
+    static CURRENT: RefCell<Option<CancellationToken>> = const { RefCell::new(None) };
+}
+
+pub(crate) fn with_token<F: FnOnce() -> T, T>(token: CancellationToken, f: F) -> T {
+    CURRENT.with(|current| *current.borrow_mut() = Some(token));
+    let result = f();
+    CURRENT.with(|current| *current.borrow_mut() = None);
+    result
+}
+
+// Long-running solvers should call this periodically so that they stop once their part times out
+pub fn check_cancelled() -> Result<()> {
+    let cancelled = CURRENT.with(|current| {
+        current
+            .borrow()
+            .as_ref()
+            .is_some_and(CancellationToken::is_cancelled)
+    });
+    if cancelled {
+        Err(Cancelled.into())
+    } else {
+        Ok(())
+    }
+}
@@ -0,0 +1,117 @@
+use anyhow::Error;
+use std::fmt::{self, Display};
+
+#[derive(Debug)]
+pub struct ParseError {
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+    pub text: String,
+    pub error: Error,
+}
+
+impl ParseError {
+    pub fn new<E: Into<Error>>(line: usize, text: &str, error: E) -> Self {
+        Self {
+            line: Some(line),
+            column: None,
+            text: text.to_string(),
+            error: error.into(),
+        }
+    }
+
+    // Locates an error within a string before it is known which line the string came from
+    pub fn in_text<E: Into<Error>>(column: usize, text: &str, error: E) -> Self {
+        Self {
+            line: None,
+            column: Some(column),
+            text: text.to_string(),
+            error: error.into(),
+        }
+    }
+
+    pub fn with_column(mut self, column: usize) -> Self {
+        self.column = Some(column);
+        self
+    }
+
+    pub fn find(error: &Error) -> Option<&Self> {
+        error.chain().find_map(|e| e.downcast_ref())
+    }
+
+    pub fn snippet(&self) -> String {
+        let number = self.line.map_or_else(String::new, |line| line.to_string());
+        let gutter = " ".repeat(number.len());
+        let mut result = format!("{} |\n{} | {}\n", gutter, number, self.text);
+        if let Some(column) = self.column {
+            result.push_str(&format!(
+                "{} | {}^\n",
+                gutter,
+                " ".repeat(column.saturating_sub(1))
+            ));
+        }
+        result
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => write!(
+                f,
+                "Failed to parse line {}, column {}: {:#}",
+                line, column, self.error
+            ),
+            (Some(line), None) => write!(f, "Failed to parse line {}: {:#}", line, self.error),
+            (None, Some(column)) => {
+                write!(f, "Failed to parse column {}: {:#}", column, self.error)
+            }
+            (None, None) => write!(f, "Failed to parse '{}': {:#}", self.text, self.error),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+// Returned by alternative solvers for inputs they can't answer exactly, so that they are skipped
+// instead of failing the part
+#[derive(Debug)]
+pub struct Unsupported(pub String);
+
+impl Display for Unsupported {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for Unsupported {}
+
+// Line and column indices are zero-based, as produced by `enumerate`. An error that already
+// has a column within the text only gains its line.
+pub trait Locate<T> {
+    fn at_line(self, line: usize, text: &str) -> Result<T, ParseError>;
+    fn at(self, line: usize, column: usize, text: &str) -> Result<T, ParseError>;
+}
+
+impl<T, E: Into<Error>> Locate<T> for Result<T, E> {
+    fn at_line(self, line: usize, text: &str) -> Result<T, ParseError> {
+        self.map_err(|e| match Into::<Error>::into(e).downcast::<ParseError>() {
+            Ok(parse_error) => ParseError {
+                line: Some(line + 1),
+                text: text.to_string(),
+                ..parse_error
+            },
+            Err(e) => ParseError::new(line + 1, text, e),
+        })
+    }
+
+    fn at(self, line: usize, column: usize, text: &str) -> Result<T, ParseError> {
+        self.map_err(|e| ParseError::new(line + 1, text, e).with_column(column + 1))
+    }
+}
+
+pub(crate) fn print_error(error: &Error) {
+    eprintln!("Error: {:#}", error);
+    if let Some(parse_error) = ParseError::find(error) {
+        eprint!("{}", parse_error.snippet());
+    }
+}
@@ -0,0 +1,301 @@
+use crate::{Allocations, Part, Report, Status, Timing};
+use anyhow::{anyhow, Error, Result};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(anyhow!(
+                "Invalid format '{}', expected 'text', 'json' or 'csv'",
+                s
+            )),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct TimingRecord {
+    pub runs: usize,
+    pub min: f64,
+    pub median: f64,
+    pub mean: f64,
+    pub stddev: f64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allocations: Option<AllocationsRecord>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct AllocationsRecord {
+    pub count: usize,
+    pub bytes: usize,
+    pub peak_bytes: usize,
+}
+
+impl From<&Allocations> for AllocationsRecord {
+    fn from(allocations: &Allocations) -> Self {
+        Self {
+            count: allocations.count,
+            bytes: allocations.bytes,
+            peak_bytes: allocations.peak,
+        }
+    }
+}
+
+impl From<&Timing> for TimingRecord {
+    fn from(timing: &Timing) -> Self {
+        Self {
+            runs: timing.runs,
+            min: timing.min.as_secs_f64(),
+            median: timing.median.as_secs_f64(),
+            mean: timing.mean.as_secs_f64(),
+            stddev: timing.stddev.as_secs_f64(),
+            allocations: timing.allocations.as_ref().map(AllocationsRecord::from),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct AlternativeRecord {
+    pub name: String,
+    pub timing: TimingRecord,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PartRecord {
+    pub part: usize,
+    pub answer: String,
+    pub status: String,
+    pub expected: Option<String>,
+    pub timing: TimingRecord,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub alternatives: Vec<AlternativeRecord>,
+}
+
+impl PartRecord {
+    fn new(part: usize, from: &Part) -> Self {
+        Self {
+            part,
+            answer: from.solution.result().trim().to_string(),
+            status: from.status.name().to_string(),
+            expected: match &from.status {
+                Status::Fail { expected } => Some(expected.clone()),
+                _ => None,
+            },
+            timing: from.solution.timing().into(),
+            alternatives: from
+                .solution
+                .alternatives()
+                .iter()
+                .map(|a| AlternativeRecord {
+                    name: a.name.to_string(),
+                    timing: (&a.timing).into(),
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ReportRecord {
+    pub day: usize,
+    pub parse: TimingRecord,
+    pub prepare: TimingRecord,
+    pub parts: Vec<PartRecord>,
+}
+
+impl From<&Report> for ReportRecord {
+    fn from(report: &Report) -> Self {
+        Self {
+            day: report.day,
+            parse: (&report.parse).into(),
+            prepare: (&report.prepare).into(),
+            parts: vec![
+                PartRecord::new(1, &report.part_one),
+                PartRecord::new(2, &report.part_two),
+            ],
+        }
+    }
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+fn csv(reports: &[Report]) -> String {
+    let mut result = String::from(
+        "day,part,answer,status,parse_seconds,prepare_seconds,solve_seconds,runs,min_seconds,mean_seconds,stddev_seconds,allocations,allocated_bytes,peak_bytes\n",
+    );
+    for record in reports.iter().map(ReportRecord::from) {
+        for part in record.parts.iter() {
+            let (allocations, allocated_bytes, peak_bytes) = match &part.timing.allocations {
+                Some(a) => (
+                    a.count.to_string(),
+                    a.bytes.to_string(),
+                    a.peak_bytes.to_string(),
+                ),
+                None => Default::default(),
+            };
+            result.push_str(&format!(
+                "{},{},{},{},{},{},{},{},{},{},{},{},{},{}\n",
+                record.day,
+                part.part,
+                csv_field(&part.answer),
+                part.status,
+                record.parse.median,
+                record.prepare.median,
+                part.timing.median,
+                part.timing.runs,
+                part.timing.min,
+                part.timing.mean,
+                part.timing.stddev,
+                allocations,
+                allocated_bytes,
+                peak_bytes,
+            ));
+        }
+    }
+    result
+}
+
+impl Format {
+    pub fn print_report(self, report: &Report) -> Result<()> {
+        match self {
+            Format::Text => print!("{}", report),
+            Format::Json => println!(
+                "{}",
+                serde_json::to_string_pretty(&ReportRecord::from(report))?
+            ),
+            Format::Csv => print!("{}", csv(std::slice::from_ref(report))),
+        }
+        Ok(())
+    }
+
+    pub fn print_reports(self, reports: &[Report]) -> Result<()> {
+        match self {
+            Format::Text => {
+                for report in reports {
+                    println!("{}", report);
+                }
+            }
+            Format::Json => println!(
+                "{}",
+                serde_json::to_string_pretty(
+                    &reports.iter().map(ReportRecord::from).collect::<Vec<_>>()
+                )?
+            ),
+            Format::Csv => print!("{}", csv(reports)),
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{csv, csv_field, ReportRecord};
+    use crate::{Part, Report, Solution, Status, Timing};
+    use serde_json::json;
+    use std::time::Duration;
+
+    fn timing() -> Timing {
+        Timing::from_samples(vec![Duration::from_millis(250)], None)
+    }
+
+    fn part(result: &str, status: Status) -> Part {
+        Part {
+            solution: Solution {
+                result: result.to_string(),
+                timing: timing(),
+                alternatives: Vec::new(),
+            },
+            status,
+        }
+    }
+
+    fn report() -> Report {
+        Report {
+            day: 13,
+            parse: timing(),
+            prepare: timing(),
+            part_one: part("17", Status::Pass),
+            part_two: part(
+                "\n#####\n#...#\n#####\n",
+                Status::Fail {
+                    expected: "O".to_string(),
+                },
+            ),
+        }
+    }
+
+    #[test]
+    fn csv_field_quoting() {
+        assert_eq!(csv_field("17"), "17");
+        assert_eq!(csv_field("1,2"), "\"1,2\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(csv_field("#.\n.#"), "\"#.\n.#\"");
+    }
+
+    #[test]
+    fn csv_multi_line_answer() {
+        let text = csv(&[report()]);
+        let mut rows = text.splitn(2, '\n');
+        assert!(rows.next().unwrap().starts_with("day,part,answer,status,"));
+        assert_eq!(
+            rows.next().unwrap(),
+            "13,1,17,PASS,0.25,0.25,0.25,1,0.25,0.25,0,,,\n\
+             13,2,\"#####\n#...#\n#####\",FAIL,0.25,0.25,0.25,1,0.25,0.25,0,,,\n"
+        );
+    }
+
+    #[test]
+    fn json_record() {
+        let timing = json!({
+            "runs": 1,
+            "min": 0.25,
+            "median": 0.25,
+            "mean": 0.25,
+            "stddev": 0.0,
+        });
+        assert_eq!(
+            serde_json::to_value(ReportRecord::from(&report())).unwrap(),
+            json!({
+                "day": 13,
+                "parse": timing,
+                "prepare": timing,
+                "parts": [
+                    {
+                        "part": 1,
+                        "answer": "17",
+                        "status": "PASS",
+                        "expected": null,
+                        "timing": timing,
+                    },
+                    {
+                        "part": 2,
+                        "answer": "#####\n#...#\n#####",
+                        "status": "FAIL",
+                        "expected": "O",
+                        "timing": timing,
+                    },
+                ],
+            })
+        );
+    }
+}
@@ -0,0 +1,379 @@
+use crate::{Input, Locate, ParseError};
+use anyhow::{anyhow, Context, Result};
+use std::{
+    fmt::{self, Display},
+    io::{self, BufRead},
+    ops::{Index, IndexMut},
+};
+
+pub const ORTHOGONAL: [(isize, isize); 4] = [(1, 0), (0, 1), (-1, 0), (0, -1)];
+pub const ADJACENT: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+pub trait Cell: Sized {
+    fn from_char(c: char) -> Option<Self>;
+    fn to_char(&self) -> char;
+}
+
+impl Cell for u8 {
+    fn from_char(c: char) -> Option<Self> {
+        c.to_digit(10).map(|d| d as u8)
+    }
+
+    fn to_char(&self) -> char {
+        char::from_digit(*self as u32, 10).unwrap_or('?')
+    }
+}
+
+impl Cell for char {
+    fn from_char(c: char) -> Option<Self> {
+        Some(c)
+    }
+
+    fn to_char(&self) -> char {
+        *self
+    }
+}
+
+impl Cell for bool {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '.' => Some(false),
+            '#' => Some(true),
+            _ => None,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        if *self {
+            '#'
+        } else {
+            '.'
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    pub fn fill(&mut self, value: T) {
+        self.cells.fill(value);
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn from_cells(width: usize, height: usize, cells: Vec<T>) -> Result<Self> {
+        if cells.len() != width * height {
+            return Err(anyhow!(
+                "Expected {} cells for a {}x{} grid, found {}",
+                width * height,
+                width,
+                height,
+                cells.len()
+            ));
+        }
+        Ok(Self {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub fn cells_mut(&mut self) -> &mut [T] {
+        &mut self.cells
+    }
+
+    pub fn index_of(&self, x: usize, y: usize) -> usize {
+        x + y * self.width
+    }
+
+    pub fn contains(&self, x: usize, y: usize) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        self.contains(x, y)
+            .then(|| &self.cells[self.index_of(x, y)])
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if self.contains(x, y) {
+            let index = self.index_of(x, y);
+            Some(&mut self.cells[index])
+        } else {
+            None
+        }
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    pub fn offset(&self, x: usize, y: usize, dx: isize, dy: isize) -> Option<(usize, usize)> {
+        let nx = x.checked_add_signed(dx)?;
+        let ny = y.checked_add_signed(dy)?;
+        self.contains(nx, ny).then_some((nx, ny))
+    }
+
+    pub fn wrapping_offset(&self, x: usize, y: usize, dx: isize, dy: isize) -> (usize, usize) {
+        (
+            (x as isize + dx).rem_euclid(self.width as isize) as usize,
+            (y as isize + dy).rem_euclid(self.height as isize) as usize,
+        )
+    }
+
+    pub fn neighbors<'a>(
+        &'a self,
+        x: usize,
+        y: usize,
+        offsets: &'a [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        offsets
+            .iter()
+            .filter_map(move |&(dx, dy)| self.offset(x, y, dx, dy))
+    }
+
+    pub fn wrapping_neighbors<'a>(
+        &'a self,
+        x: usize,
+        y: usize,
+        offsets: &'a [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        offsets
+            .iter()
+            .map(move |&(dx, dy)| self.wrapping_offset(x, y, dx, dy))
+    }
+
+    pub fn neighbors4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbors(x, y, &ORTHOGONAL)
+    }
+
+    pub fn neighbors8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbors(x, y, &ADJACENT)
+    }
+
+    pub fn wrapping_neighbors4(
+        &self,
+        x: usize,
+        y: usize,
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.wrapping_neighbors(x, y, &ORTHOGONAL)
+    }
+
+    pub fn wrapping_neighbors8(
+        &self,
+        x: usize,
+        y: usize,
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.wrapping_neighbors(x, y, &ADJACENT)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        &mut self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "Column {} is out of bounds", x);
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |x| self.column(x))
+    }
+}
+
+impl<T: Cell> Grid<T> {
+    pub fn from_lines<I: Iterator<Item = io::Result<String>>>(lines: I) -> Result<Self> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+        for (y, line) in lines.enumerate() {
+            let line = line.context("Failed to read line")?;
+            let start = cells.len();
+            for (x, c) in line.chars().enumerate() {
+                cells.push(
+                    T::from_char(c)
+                        .ok_or_else(|| anyhow!("Invalid character '{}'", c))
+                        .at(y, x, &line)?,
+                );
+            }
+
+            let line_width = cells.len() - start;
+            match width {
+                None => width = Some(line_width),
+                Some(width) if width != line_width => {
+                    return Err(ParseError::new(
+                        y + 1,
+                        &line,
+                        anyhow!("Line has width {}, expected {}", line_width, width),
+                    )
+                    .into())
+                }
+                _ => (),
+            }
+            height += 1;
+        }
+
+        match width {
+            None | Some(0) => Err(anyhow!("Empty grid")),
+            Some(width) => Ok(Self {
+                width,
+                height,
+                cells,
+            }),
+        }
+    }
+}
+
+impl<T: Cell> Input for Grid<T> {
+    fn parse<R: BufRead>(reader: R) -> Result<Self> {
+        Self::from_lines(reader.lines())
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        assert!(self.contains(x, y), "({}, {}) is out of bounds", x, y);
+        &self.cells[self.index_of(x, y)]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        assert!(self.contains(x, y), "({}, {}) is out of bounds", x, y);
+        let index = self.index_of(x, y);
+        &mut self.cells[index]
+    }
+}
+
+impl<T: Cell> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            writeln!(f, "{}", row.iter().map(T::to_char).collect::<String>())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Grid;
+    use crate::{Input, ParseError};
+
+    fn grid() -> Grid<u8> {
+        Grid::from_cells(3, 2, vec![0, 1, 2, 3, 4, 5]).unwrap()
+    }
+
+    #[test]
+    fn column() {
+        let grid = grid();
+        assert_eq!(grid.column(0).copied().collect::<Vec<_>>(), [0, 3]);
+        assert_eq!(grid.column(2).copied().collect::<Vec<_>>(), [2, 5]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Column 3 is out of bounds")]
+    fn column_out_of_bounds() {
+        let _ = grid().column(3);
+    }
+
+    #[test]
+    #[should_panic(expected = "Column 0 is out of bounds")]
+    fn column_of_empty_grid() {
+        let _ = Grid::<u8>::from_cells(0, 0, Vec::new()).unwrap().column(0);
+    }
+
+    #[test]
+    fn parse() {
+        let grid = Grid::<u8>::parse("012\n345\n".as_bytes()).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.cells(), [0, 1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn parse_width_mismatch() {
+        let error = Grid::<u8>::parse("012\n34\n".as_bytes()).unwrap_err();
+        let parse_error = ParseError::find(&error).unwrap();
+        assert_eq!(parse_error.line, Some(2));
+        assert_eq!(parse_error.text, "34");
+        assert_eq!(
+            parse_error.to_string(),
+            "Failed to parse line 2: Line has width 2, expected 3"
+        );
+    }
+
+    #[test]
+    fn parse_invalid_character() {
+        let error = Grid::<u8>::parse("012\n3x5\n".as_bytes()).unwrap_err();
+        let parse_error = ParseError::find(&error).unwrap();
+        assert_eq!((parse_error.line, parse_error.column), (Some(2), Some(2)));
+    }
+
+    #[test]
+    fn parse_empty() {
+        for input in ["", "\n", "\n\n"] {
+            let error = Grid::<u8>::parse(input.as_bytes()).unwrap_err();
+            assert_eq!(error.to_string(), "Empty grid");
+        }
+    }
+}
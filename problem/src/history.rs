@@ -0,0 +1,317 @@
+use crate::{inputs::workspace_dir, Report, ReportRecord, TimingRecord};
+use anyhow::{anyhow, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::{
+    env,
+    fs::{self, OpenOptions},
+    io::{ErrorKind, Write},
+    path::{Path, PathBuf},
+    process::Command,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+// Welch's t statistic above which a difference in mean time is treated as significant,
+// roughly a 95% confidence level for the sample sizes that benchmarks produce
+const SIGNIFICANCE: f64 = 2.0;
+// Smallest relative change in mean time worth reporting, since long benchmarks make even
+// tiny differences significant
+const MIN_CHANGE: f64 = 0.05;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub(crate) struct HistoryEntry {
+    pub revision: Option<String>,
+    pub timestamp: u64,
+    #[serde(default)]
+    pub input: String,
+    #[serde(default)]
+    pub params: Vec<(String, String)>,
+    pub report: ReportRecord,
+}
+
+impl HistoryEntry {
+    // Only benchmarks of the same input with the same parameters are comparable
+    fn matches(&self, other: &HistoryEntry) -> bool {
+        self.report.day == other.report.day
+            && self.input == other.input
+            && self.params == other.params
+    }
+}
+
+fn history_path() -> Result<PathBuf> {
+    let target = match env::var_os("CARGO_TARGET_DIR") {
+        Some(target) => PathBuf::from(target),
+        None => workspace_dir()?.join("target"),
+    };
+    Ok(target.join("bench_history.jsonl"))
+}
+
+fn git(args: &[&str]) -> Option<String> {
+    let output = Command::new("git").args(args).output().ok()?;
+    if output.status.success() {
+        Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    } else {
+        None
+    }
+}
+
+fn revision() -> Option<String> {
+    let revision = git(&["rev-parse", "--short", "HEAD"])?;
+    let dirty = git(&["status", "--porcelain", "--untracked-files=no"])
+        .is_some_and(|status| !status.is_empty());
+    Some(if dirty {
+        format!("{}-dirty", revision)
+    } else {
+        revision
+    })
+}
+
+fn input_name(path: &Path) -> String {
+    fs::canonicalize(path)
+        .unwrap_or_else(|_| path.to_path_buf())
+        .display()
+        .to_string()
+}
+
+pub(crate) fn save(
+    inputs: &[PathBuf],
+    reports: &[Report],
+    params: &[(String, String)],
+) -> Result<()> {
+    let path = history_path()?;
+    let mut params = params.to_vec();
+    params.sort();
+    let revision = revision();
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+
+    let mut lines = String::new();
+    for (input, report) in inputs.iter().zip(reports) {
+        let entry = HistoryEntry {
+            revision: revision.clone(),
+            timestamp,
+            input: input_name(input),
+            params: params.clone(),
+            report: report.into(),
+        };
+        lines.push_str(&serde_json::to_string(&entry)?);
+        lines.push('\n');
+    }
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .and_then(|mut file| file.write_all(lines.as_bytes()))
+        .with_context(|| format!("Failed to write {}", path.display()))
+}
+
+pub(crate) fn load() -> Result<Vec<HistoryEntry>> {
+    let path = history_path()?;
+    let contents = match fs::read_to_string(&path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e).with_context(|| format!("Failed to read {}", path.display())),
+    };
+    contents
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            serde_json::from_str(line)
+                .with_context(|| format!("Failed to parse line {} of {}", i + 1, path.display()))
+        })
+        .collect()
+}
+
+fn welch_t(baseline: &TimingRecord, current: &TimingRecord) -> f64 {
+    let variance = baseline.stddev.powi(2) / baseline.runs as f64
+        + current.stddev.powi(2) / current.runs as f64;
+    let difference = current.mean - baseline.mean;
+    if variance == 0.0 {
+        if difference == 0.0 {
+            0.0
+        } else {
+            difference.signum() * f64::INFINITY
+        }
+    } else {
+        difference / variance.sqrt()
+    }
+}
+
+fn describe(entry: &HistoryEntry) -> &str {
+    entry.revision.as_deref().unwrap_or("unknown revision")
+}
+
+// Pairs the latest benchmark of each day, input and set of parameters with the one before it
+fn comparisons(
+    history: &[HistoryEntry],
+    day: Option<usize>,
+) -> Vec<(&HistoryEntry, &HistoryEntry)> {
+    let mut latest = Vec::<&HistoryEntry>::new();
+    for entry in history.iter().rev() {
+        if day.is_none_or(|day| day == entry.report.day) && !latest.iter().any(|e| e.matches(entry))
+        {
+            latest.push(entry);
+        }
+    }
+    latest.sort_by(|a, b| (a.report.day, &a.input).cmp(&(b.report.day, &b.input)));
+
+    latest
+        .into_iter()
+        .filter_map(|current| {
+            let baseline = history.iter().rev().filter(|e| e.matches(current)).nth(1)?;
+            Some((current, baseline))
+        })
+        .collect()
+}
+
+pub(crate) fn compare(day: Option<usize>) -> Result<()> {
+    let history = load()?;
+
+    let mut slowdowns = 0;
+    let mut compared = 0;
+    for (current, baseline) in comparisons(&history, day) {
+        print!("Day {} with {}", current.report.day, current.input);
+        for (name, value) in current.params.iter() {
+            print!(" {}={}", name, value);
+        }
+        println!(" ({} vs {}):", describe(current), describe(baseline));
+        for part in current.report.parts.iter() {
+            let previous = match baseline.report.parts.iter().find(|p| p.part == part.part) {
+                Some(previous) => previous,
+                None => continue,
+            };
+            if part.status == "TIMEOUT" || previous.status == "TIMEOUT" {
+                println!("  Part {}: timed out", part.part);
+                continue;
+            }
+            if previous.timing.mean <= 0.0 {
+                println!("  Part {}: no previous timing to compare", part.part);
+                continue;
+            }
+
+            let t = welch_t(&previous.timing, &part.timing);
+            let change = part.timing.mean / previous.timing.mean - 1.0;
+            let verdict = if t > SIGNIFICANCE && change > MIN_CHANGE {
+                slowdowns += 1;
+                "SLOWER"
+            } else if t < -SIGNIFICANCE && change < -MIN_CHANGE {
+                "faster"
+            } else {
+                "no significant change"
+            };
+            println!(
+                "  Part {}: {} -> {} seconds ({:+.1}%) {}",
+                part.part,
+                previous.timing.mean,
+                part.timing.mean,
+                change * 100.0,
+                verdict
+            );
+            compared += 1;
+        }
+    }
+
+    if compared == 0 {
+        Err(anyhow!(
+            "Not enough benchmark history to compare, run with --bench at least twice"
+        ))
+    } else if slowdowns > 0 {
+        Err(anyhow!("{} part(s) got significantly slower", slowdowns))
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{comparisons, welch_t, HistoryEntry};
+    use crate::{ReportRecord, TimingRecord};
+
+    fn timing(mean: f64, stddev: f64, runs: usize) -> TimingRecord {
+        TimingRecord {
+            runs,
+            min: mean,
+            median: mean,
+            mean,
+            stddev,
+            allocations: None,
+        }
+    }
+
+    fn entry(revision: &str, day: usize, input: &str, params: &[(&str, &str)]) -> HistoryEntry {
+        HistoryEntry {
+            revision: Some(revision.to_string()),
+            timestamp: 0,
+            input: input.to_string(),
+            params: params
+                .iter()
+                .map(|&(name, value)| (name.to_string(), value.to_string()))
+                .collect(),
+            report: ReportRecord {
+                day,
+                parse: timing(0.0, 0.0, 1),
+                prepare: timing(0.0, 0.0, 1),
+                parts: Vec::new(),
+            },
+        }
+    }
+
+    #[test]
+    fn welch_t_statistic() {
+        let t = welch_t(&timing(1.0, 0.1, 100), &timing(1.1, 0.1, 100));
+        assert!((t - 0.1 / 0.0002f64.sqrt()).abs() < 1e-9);
+        assert!(welch_t(&timing(1.1, 0.1, 100), &timing(1.0, 0.1, 100)) < 0.0);
+    }
+
+    #[test]
+    fn welch_t_zero_variance() {
+        assert_eq!(welch_t(&timing(1.0, 0.0, 1), &timing(1.0, 0.0, 1)), 0.0);
+        assert_eq!(
+            welch_t(&timing(1.0, 0.0, 1), &timing(2.0, 0.0, 1)),
+            f64::INFINITY
+        );
+        assert_eq!(
+            welch_t(&timing(2.0, 0.0, 1), &timing(1.0, 0.0, 1)),
+            f64::NEG_INFINITY
+        );
+    }
+
+    #[test]
+    fn matches() {
+        let current = entry("a", 1, "input.txt", &[("steps", "10")]);
+        assert!(current.matches(&entry("b", 1, "input.txt", &[("steps", "10")])));
+        assert!(!current.matches(&entry("b", 2, "input.txt", &[("steps", "10")])));
+        assert!(!current.matches(&entry("b", 1, "example_1.txt", &[("steps", "10")])));
+        assert!(!current.matches(&entry("b", 1, "input.txt", &[("steps", "20")])));
+        assert!(!current.matches(&entry("b", 1, "input.txt", &[])));
+    }
+
+    #[test]
+    fn compare_previous_matching_run() {
+        let history = vec![
+            entry("a", 1, "input.txt", &[]),
+            entry("a", 2, "input.txt", &[]),
+            entry("b", 1, "input.txt", &[]),
+            entry("c", 1, "input.txt", &[("steps", "10")]),
+            entry("c", 1, "example_1.txt", &[]),
+            entry("d", 1, "input.txt", &[]),
+        ];
+        let pairs = comparisons(&history, None)
+            .into_iter()
+            .map(|(current, baseline)| {
+                (
+                    current.revision.as_deref().unwrap(),
+                    baseline.revision.as_deref().unwrap(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(pairs, [("d", "b")]);
+
+        assert!(comparisons(&history, Some(2)).is_empty());
+        assert_eq!(comparisons(&history, Some(1)).len(), 1);
+    }
+}
@@ -0,0 +1,84 @@
+use anyhow::{anyhow, Context, Result};
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+// Inputs live in the workspace at inputs/day_N/, with the puzzle input in input.txt and any
+// examples in example_*.txt
+
+pub(crate) fn workspace_dir() -> Result<PathBuf> {
+    let current = env::current_dir()?;
+    for directory in current.ancestors() {
+        let manifest = directory.join("Cargo.toml");
+        if manifest.exists() && fs::read_to_string(&manifest)?.contains("[workspace]") {
+            return Ok(directory.to_path_buf());
+        }
+    }
+    Err(anyhow!(
+        "Could not find the workspace manifest from {}",
+        current.display()
+    ))
+}
+
+pub fn inputs_dir() -> Result<PathBuf> {
+    Ok(workspace_dir()?.join("inputs"))
+}
+
+pub(crate) fn day_dir(inputs: &Path, day: usize) -> PathBuf {
+    inputs.join(format!("day_{}", day))
+}
+
+pub fn input_path(day: usize) -> Result<PathBuf> {
+    Ok(day_dir(&inputs_dir()?, day).join("input.txt"))
+}
+
+pub fn example_paths(day: usize) -> Result<Vec<PathBuf>> {
+    let directory = day_dir(&inputs_dir()?, day);
+    let entries = match fs::read_dir(&directory) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e).with_context(|| format!("Failed to read {}", directory.display())),
+    };
+
+    let mut paths = Vec::new();
+    for entry in entries {
+        let path = entry?.path();
+        let is_example = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| name.starts_with("example_") && name.ends_with(".txt"));
+        if is_example {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+    Ok(paths)
+}
+
+// Resolves the inputs to run for a day from an optional explicit path
+pub(crate) fn resolve(day: usize, path: Option<&str>, examples: bool) -> Result<Vec<PathBuf>> {
+    match path {
+        Some(_) if examples => Err(anyhow!("--examples cannot be used with an input path")),
+        Some(path) => Ok(vec![PathBuf::from(path)]),
+        None if examples => {
+            let paths = example_paths(day)?;
+            if paths.is_empty() {
+                Err(anyhow!(
+                    "No examples found in {}",
+                    day_dir(&inputs_dir()?, day).display()
+                ))
+            } else {
+                Ok(paths)
+            }
+        }
+        None => {
+            let path = input_path(day)?;
+            if path.exists() {
+                Ok(vec![path])
+            } else {
+                Err(anyhow!("No input found at {}", path.display()))
+            }
+        }
+    }
+}
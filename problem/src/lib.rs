@@ -3,27 +3,77 @@ use std::{
     env,
     fmt::{self, Display},
     fs::File,
-    io::{BufRead, BufReader},
+    hint::black_box,
+    io::{self, BufRead, Read},
     path::Path,
-    str::{from_utf8, FromStr},
-    time::{Duration, Instant},
+    process,
+    str::FromStr,
+    sync::{
+        mpsc::{self, RecvTimeoutError},
+        Arc,
+    },
+    thread,
+    time::Duration,
 };
 
+mod alloc;
+mod answers;
+mod cancel;
+mod error;
+mod format;
+mod grid;
+mod history;
+mod inputs;
+mod options;
+mod params;
+mod pattern;
+mod runner;
+mod scaffold;
+mod sections;
+mod timing;
+mod unparse;
+mod visualize;
+
+pub mod testing;
+
+pub use alloc::Allocations;
+pub use answers::{Answers, Status};
+pub use cancel::{check_cancelled, CancellationToken, Cancelled, TimedOut};
+pub use error::{Locate, ParseError, Unsupported};
+pub use format::{
+    AllocationsRecord, AlternativeRecord, Format, PartRecord, ReportRecord, TimingRecord,
+};
+pub use grid::{Cell, Grid, ADJACENT, ORTHOGONAL};
+pub use inputs::{example_paths, input_path, inputs_dir};
+pub use options::Options;
+pub use params::Params;
+pub use pattern::{scan, scan_any, unscan, Captures};
+pub use runner::{run_main, Day};
+pub use sections::Sections;
+pub use timing::{Bench, Budget, Timing};
+pub use unparse::Unparse;
+pub use visualize::{Ansi, Frame, Ppm, Renderer, Rgb, Visualize};
+
+#[doc(hidden)]
+pub mod __private {
+    pub use anyhow::{anyhow, Error};
+}
+
 pub trait Input: Sized {
     fn parse<R: BufRead>(reader: R) -> Result<Self>;
 }
 
 impl<T: FromStr> Input for Vec<T>
 where
-    T::Err: Display,
+    T::Err: Into<Error>,
 {
     fn parse<R: BufRead>(reader: R) -> Result<Self> {
         reader
             .lines()
             .enumerate()
             .map(|(line_number, line)| {
-                T::from_str(&line.context("Failed to read line")?)
-                    .map_err(|e| anyhow!("Failed to parse line {}: {}", line_number + 1, e))
+                let line = line.context("Failed to read line")?;
+                Ok(T::from_str(&line).at_line(line_number, &line)?)
             })
             .collect()
     }
@@ -37,15 +87,45 @@ impl Display for Unimplemented {
     }
 }
 
+pub type Solver<P, T> =
+    fn(&<P as Problem>::Input, &<P as Problem>::Shared, &<P as Problem>::Params) -> Result<T>;
+
 pub trait Problem {
-    type Input: Input;
-    type PartOne: Display;
-    type PartTwo: Display;
+    const DAY: usize;
 
-    fn solve_part_one(input: &Self::Input) -> Self::PartOne;
-    fn solve_part_two(input: &Self::Input) -> Self::PartTwo;
+    type Input: Input + Send + Sync + 'static;
+    type Params: Params + Send + 'static;
+    type Shared: Default + Send + Sync + 'static;
+    type PartOne: Display + Send + 'static;
+    type PartTwo: Display + Send + 'static;
+
+    // Precomputes state shared by both parts, which most problems leave as ()
+    fn prepare(_input: &Self::Input, _params: &Self::Params) -> Result<Self::Shared> {
+        Ok(Self::Shared::default())
+    }
+
+    fn solve_part_one(
+        input: &Self::Input,
+        shared: &Self::Shared,
+        params: &Self::Params,
+    ) -> Result<Self::PartOne>;
+    fn solve_part_two(
+        input: &Self::Input,
+        shared: &Self::Shared,
+        params: &Self::Params,
+    ) -> Result<Self::PartTwo>;
+
+    // Alternative implementations run after the main solver and must give the same answer
+    fn part_one_alternatives() -> Vec<(&'static str, Solver<Self, Self::PartOne>)> {
+        Vec::new()
+    }
+
+    fn part_two_alternatives() -> Vec<(&'static str, Solver<Self, Self::PartTwo>)> {
+        Vec::new()
+    }
 }
 
+#[derive(Clone, Debug)]
 pub struct CSV<T>(Vec<T>);
 
 impl<T> CSV<T> {
@@ -54,6 +134,12 @@ impl<T> CSV<T> {
     }
 }
 
+impl<T> From<Vec<T>> for CSV<T> {
+    fn from(values: Vec<T>) -> Self {
+        Self(values)
+    }
+}
+
 impl<T: FromStr> FromStr for CSV<T>
 where
     T::Err: std::error::Error + Send + Sync + 'static,
@@ -69,56 +155,578 @@ impl<T: FromStr> Input for CSV<T>
 where
     T::Err: std::error::Error + Send + Sync + 'static,
 {
-    fn parse<R: BufRead>(reader: R) -> Result<Self> {
-        let values = reader
-            .split(b',')
-            .map(|x| Ok(from_utf8(&x?)?.parse()?))
+    fn parse<R: BufRead>(mut reader: R) -> Result<Self> {
+        let mut text = String::new();
+        reader.read_to_string(&mut text)?;
+        if text.trim().is_empty() {
+            return Ok(Self(Vec::new()));
+        }
+        let values = text
+            .split(',')
+            .map(|x| Ok(x.trim().parse()?))
             .collect::<Result<_, Error>>()?;
         Ok(Self(values))
     }
 }
 
+#[derive(Clone, Debug)]
+pub struct Alternative {
+    pub name: &'static str,
+    pub timing: Timing,
+}
+
 pub struct Solution<T> {
     result: T,
-    duration: Duration,
+    timing: Timing,
+    alternatives: Vec<Alternative>,
 }
 
 impl<T: Display> Display for Solution<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         writeln!(f, "  Solution: {}", self.result)?;
-        writeln!(f, "  Elapsed:  {} seconds", self.duration.as_secs_f64())?;
+        write!(f, "{}", self.timing)?;
+        for alternative in self.alternatives.iter() {
+            writeln!(f, "  Alternative: {}", alternative.name)?;
+            write!(f, "{}", alternative.timing)?;
+        }
         Ok(())
     }
 }
 
-fn time_solve<F: FnOnce() -> T, T>(f: F) -> Solution<T> {
-    let start = Instant::now();
-    let result = f();
-    let duration = Instant::now().duration_since(start);
-    Solution { result, duration }
+impl<T> Solution<T> {
+    pub fn result(&self) -> &T {
+        &self.result
+    }
+
+    pub fn timing(&self) -> &Timing {
+        &self.timing
+    }
+
+    pub fn duration(&self) -> Duration {
+        self.timing.median
+    }
+
+    pub fn alternatives(&self) -> &[Alternative] {
+        &self.alternatives
+    }
+}
+
+impl<T: Display> Solution<T> {
+    fn erase(&self) -> Solution<String> {
+        Solution {
+            result: self.result.to_string(),
+            timing: self.timing,
+            alternatives: self.alternatives.clone(),
+        }
+    }
 }
 
-pub type SolveResult<P> = Result<(
-    Solution<<P as Problem>::PartOne>,
-    Solution<<P as Problem>::PartTwo>,
-)>;
+fn time_phase<F: FnMut() -> T, T>(options: &Options, f: F) -> (T, Timing) {
+    match options.bench {
+        Some(bench) => timing::bench(&bench, f),
+        None => timing::time(f),
+    }
+}
+
+fn time_solve<P: Problem, T: Display>(
+    options: &Options,
+    solver: Solver<P, T>,
+    alternatives: &[(&'static str, Solver<P, T>)],
+    input: &P::Input,
+    shared: &P::Shared,
+    params: &P::Params,
+) -> Result<Solution<T>> {
+    let (result, timing) = time_phase(options, || {
+        solver(black_box(input), black_box(shared), params)
+    });
+    let result = result?;
+
+    let expected = result.to_string();
+    let mut timings = Vec::new();
+    for &(name, alternative) in alternatives.iter() {
+        let (answer, timing) = time_phase(options, || {
+            alternative(black_box(input), black_box(shared), params)
+        });
+        let answer = match answer {
+            Err(e) if e.is::<Unsupported>() => continue,
+            answer => answer
+                .with_context(|| format!("Alternative solver '{}' failed", name))?
+                .to_string(),
+        };
+        if answer.trim() != expected.trim() {
+            return Err(anyhow!(
+                "Alternative solver '{}' answered {}, but the main solver answered {}",
+                name,
+                answer.trim(),
+                expected.trim()
+            ));
+        }
+        timings.push(Alternative { name, timing });
+    }
+
+    Ok(Solution {
+        result,
+        timing,
+        alternatives: timings,
+    })
+}
+
+// With a timeout, a phase runs once on a worker thread first, and only benchmarks once that
+// finishes in time. A timed out worker is cancelled and abandoned rather than joined, so
+// solvers that never poll check_cancelled keep running in the background until they finish
+// or the process exits.
+fn with_timeout<T: Send + 'static>(
+    options: &Options,
+    run: impl Fn(&Options) -> Result<T> + Clone + Send + 'static,
+) -> Result<Result<T, TimedOut>> {
+    let timeout = match options.timeout {
+        Some(timeout) => timeout,
+        None => return Ok(Ok(run(options)?)),
+    };
+
+    let token = CancellationToken::new();
+    let (sender, receiver) = mpsc::channel();
+    let worker = {
+        let options = Options {
+            bench: None,
+            ..options.clone()
+        };
+        let token = token.clone();
+        let run = run.clone();
+        thread::spawn(move || {
+            let result = cancel::with_token(token, || run(&options));
+            let _ = sender.send(result);
+        })
+    };
+
+    let result = match receiver.recv_timeout(timeout) {
+        Ok(result) => result?,
+        Err(RecvTimeoutError::Timeout) => {
+            token.cancel();
+            return Ok(Err(TimedOut(timeout)));
+        }
+        Err(RecvTimeoutError::Disconnected) => return Err(anyhow!("Solver thread panicked")),
+    };
+    let _ = worker.join();
+
+    if options.bench.is_some() {
+        Ok(Ok(run(options)?))
+    } else {
+        Ok(Ok(result))
+    }
+}
+
+fn run_part<P: Problem, T: Display + Send + 'static>(
+    options: &Options,
+    solver: Solver<P, T>,
+    alternatives: Vec<(&'static str, Solver<P, T>)>,
+    input: &Arc<P::Input>,
+    shared: &Arc<P::Shared>,
+    params: &Arc<P::Params>,
+) -> Result<Result<Solution<T>, TimedOut>> {
+    let (input, shared, params) = (input.clone(), shared.clone(), params.clone());
+    with_timeout(options, move |options| {
+        time_solve::<P, T>(options, solver, &alternatives, &input, &shared, &params)
+    })
+}
+
+pub struct Solutions<P: Problem> {
+    pub parse: Timing,
+    pub prepare: Timing,
+    pub part_one: Result<Solution<P::PartOne>, TimedOut>,
+    pub part_two: Result<Solution<P::PartTwo>, TimedOut>,
+}
+
+pub type SolveResult<P> = Result<Solutions<P>>;
+
+const STDIN: &str = "-";
+
+fn is_stdin(path: &Path) -> bool {
+    path == Path::new(STDIN)
+}
+
+fn read_input(path: &Path) -> Result<Vec<u8>> {
+    let mut bytes = Vec::new();
+    if is_stdin(path) {
+        io::stdin()
+            .lock()
+            .read_to_end(&mut bytes)
+            .context("Failed to read input from stdin")?;
+    } else {
+        File::open(path)
+            .context("Failed to open input file")?
+            .read_to_end(&mut bytes)
+            .context("Failed to read input file")?;
+    }
+    Ok(bytes)
+}
 
 pub fn solve<P: Problem>(path: &Path) -> SolveResult<P> {
-    let input_file = BufReader::new(File::open(path).context("Failed to open input file")?);
-    let input = Input::parse(input_file).context("Failed to parse input")?;
+    solve_with::<P>(path, &Options::default())
+}
+
+pub fn solve_with<P: Problem>(path: &Path, options: &Options) -> SolveResult<P> {
+    solve_bytes::<P>(&read_input(path)?, options)
+}
+
+pub fn solve_str<P: Problem>(input: &str) -> SolveResult<P> {
+    solve_bytes::<P>(input.as_bytes(), &Options::default())
+}
+
+pub fn solve_reader<P: Problem, R: BufRead>(mut reader: R) -> SolveResult<P> {
+    let mut bytes = Vec::new();
+    reader
+        .read_to_end(&mut bytes)
+        .context("Failed to read input")?;
+    solve_bytes::<P>(&bytes, &Options::default())
+}
+
+fn solve_bytes<P: Problem>(bytes: &[u8], options: &Options) -> SolveResult<P> {
+    let mut params = P::Params::default();
+    for (name, value) in options.params.iter() {
+        params.set(name, value)?;
+    }
+
+    let (input, parse) = time_phase(options, || P::Input::parse(black_box(bytes)));
+    let input = input.context("Failed to parse input")?;
+
+    let (input, params) = (Arc::new(input), Arc::new(params));
+    let prepared = {
+        let (input, params) = (input.clone(), params.clone());
+        with_timeout(options, move |options| {
+            let (shared, timing) = time_phase(options, || P::prepare(black_box(&input), &params));
+            Ok((shared.context("Failed to prepare input")?, timing))
+        })?
+    };
+    // Both parts depend on the shared state, so they time out along with preparing it
+    let (shared, prepare) = match prepared {
+        Ok((shared, prepare)) => (Arc::new(shared), prepare),
+        Err(timed_out) => {
+            return Ok(Solutions {
+                parse,
+                prepare: Timing::from_samples(vec![timed_out.0], None),
+                part_one: Err(timed_out),
+                part_two: Err(timed_out),
+            })
+        }
+    };
+
+    let part_one = || {
+        run_part::<P, _>(
+            options,
+            P::solve_part_one,
+            P::part_one_alternatives(),
+            &input,
+            &shared,
+            &params,
+        )
+        .context("Failed to solve part one")
+    };
+    let part_two = || {
+        run_part::<P, _>(
+            options,
+            P::solve_part_two,
+            P::part_two_alternatives(),
+            &input,
+            &shared,
+            &params,
+        )
+        .context("Failed to solve part two")
+    };
+    let (part_one, part_two) = if options.parallel {
+        rayon::join(part_one, part_two)
+    } else {
+        (part_one(), part_two())
+    };
 
-    Ok((
-        time_solve(|| P::solve_part_one(&input)),
-        time_solve(|| P::solve_part_two(&input)),
-    ))
+    Ok(Solutions {
+        parse,
+        prepare,
+        part_one: part_one?,
+        part_two: part_two?,
+    })
+}
+
+pub struct Part {
+    pub solution: Solution<String>,
+    pub status: Status,
+}
+
+impl Part {
+    fn new<T: Display>(
+        solution: Result<Solution<T>, TimedOut>,
+        check: impl FnOnce(&str) -> Status,
+    ) -> Self {
+        match solution {
+            Ok(solution) => {
+                let solution = solution.erase();
+                Self {
+                    status: check(solution.result()),
+                    solution,
+                }
+            }
+            Err(TimedOut(timeout)) => Self {
+                solution: Solution {
+                    result: String::new(),
+                    timing: Timing::from_samples(vec![timeout], None),
+                    alternatives: Vec::new(),
+                },
+                status: Status::Timeout,
+            },
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.status.is_timeout() {
+            writeln!(
+                f,
+                "  Timed out after {} seconds",
+                self.solution.duration().as_secs_f64()
+            )?;
+        } else {
+            write!(f, "{}", self.solution)?;
+        }
+        writeln!(f, "  Status:   {}", self.status)
+    }
+}
+
+pub struct Report {
+    pub day: usize,
+    pub parse: Timing,
+    pub prepare: Timing,
+    pub part_one: Part,
+    pub part_two: Part,
+}
+
+impl Report {
+    pub fn duration(&self) -> Duration {
+        self.parse.median
+            + self.prepare.median
+            + self.part_one.solution.duration()
+            + self.part_two.solution.duration()
+    }
+
+    pub fn is_fail(&self) -> bool {
+        self.part_one.status.is_fail() || self.part_two.status.is_fail()
+    }
+
+    pub fn is_timeout(&self) -> bool {
+        self.part_one.status.is_timeout() || self.part_two.status.is_timeout()
+    }
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Day {}:", self.day)?;
+        writeln!(f, "Parse:")?;
+        writeln!(f, "{}", self.parse)?;
+        writeln!(f, "Prepare:")?;
+        writeln!(f, "{}", self.prepare)?;
+        writeln!(f, "Part one:")?;
+        writeln!(f, "{}", self.part_one)?;
+        writeln!(f, "Part two:")?;
+        writeln!(f, "{}", self.part_two)?;
+        Ok(())
+    }
+}
+
+pub fn report<P: Problem>(path: &Path, options: &Options) -> Result<Report> {
+    let solutions = solve_with::<P>(path, options)?;
+
+    // Recorded answers only hold for the puzzle's own parameters
+    let answers = if is_stdin(path) || !options.params.is_empty() {
+        Answers::default()
+    } else {
+        Answers::load(path)?
+    };
+    let report = Report {
+        day: P::DAY,
+        parse: solutions.parse,
+        prepare: solutions.prepare,
+        part_one: Part::new(solutions.part_one, |r| answers.check_part_one(r)),
+        part_two: Part::new(solutions.part_two, |r| answers.check_part_two(r)),
+    };
+
+    if options.record {
+        if is_stdin(path) {
+            return Err(anyhow!("Cannot record answers for input read from stdin"));
+        }
+        if !options.params.is_empty() {
+            return Err(anyhow!("Cannot record answers with custom parameters"));
+        }
+        if report.is_timeout() {
+            return Err(anyhow!("Cannot record answers for parts that timed out"));
+        }
+        Answers {
+            part_one: Some(report.part_one.solution.result().trim().to_string()),
+            part_two: Some(report.part_two.solution.result().trim().to_string()),
+        }
+        .save(path)?;
+    }
+
+    Ok(report)
+}
+
+fn try_solve_main<P: Problem>() -> Result<()> {
+    let (args, options) = Options::parse(env::args().skip(1))?;
+    match args.as_slice() {
+        [] => runner::run_one(&Day::of::<P>(), None, &options),
+        [path] => runner::run_one(&Day::of::<P>(), Some(path), &options),
+        _ => Err(anyhow!(
+            "Too many arguments, expected at most an input path"
+        )),
+    }
 }
 
 pub fn solve_main<P: Problem>() {
-    let path = env::args().nth(1).expect("missing input file path");
-    let (part_one, part_two) = solve::<P>(path.as_ref()).expect("failed to solve problem");
+    if let Err(e) = try_solve_main::<P>() {
+        error::print_error(&e);
+        process::exit(1);
+    }
+}
 
-    println!("Part one:");
-    println!("{}", part_one);
-    println!("Part two:");
-    println!("{}", part_two);
+#[cfg(test)]
+mod tests {
+    use super::{solve_bytes, Options, Problem, Solver, Unsupported};
+    use anyhow::{anyhow, Result};
+    use std::{
+        thread,
+        time::{Duration, Instant},
+    };
+
+    // Neither problem polls check_cancelled, so timing out must not wait for them to finish
+    const SLEEP: Duration = Duration::from_secs(10);
+
+    fn options() -> Options {
+        Options {
+            timeout: Some(Duration::from_millis(50)),
+            ..Options::default()
+        }
+    }
+
+    struct SlowPart;
+
+    impl Problem for SlowPart {
+        const DAY: usize = 0;
+
+        type Input = Vec<u64>;
+        type Params = ();
+        type Shared = ();
+        type PartOne = u64;
+        type PartTwo = u64;
+
+        fn solve_part_one(_input: &Self::Input, _shared: &(), _params: &()) -> Result<u64> {
+            thread::sleep(SLEEP);
+            Ok(0)
+        }
+
+        fn solve_part_two(input: &Self::Input, _shared: &(), _params: &()) -> Result<u64> {
+            Ok(input.iter().sum())
+        }
+    }
+
+    struct SlowPrepare;
+
+    impl Problem for SlowPrepare {
+        const DAY: usize = 0;
+
+        type Input = Vec<u64>;
+        type Params = ();
+        type Shared = ();
+        type PartOne = u64;
+        type PartTwo = u64;
+
+        fn prepare(_input: &Self::Input, _params: &()) -> Result<()> {
+            thread::sleep(SLEEP);
+            Ok(())
+        }
+
+        fn solve_part_one(_input: &Self::Input, _shared: &(), _params: &()) -> Result<u64> {
+            Ok(0)
+        }
+
+        fn solve_part_two(_input: &Self::Input, _shared: &(), _params: &()) -> Result<u64> {
+            Ok(0)
+        }
+    }
+
+    // Part one's alternative can't handle large inputs, and part two's fails on zeros
+    struct Sum;
+
+    impl Problem for Sum {
+        const DAY: usize = 0;
+
+        type Input = Vec<u64>;
+        type Params = ();
+        type Shared = ();
+        type PartOne = u64;
+        type PartTwo = u64;
+
+        fn solve_part_one(input: &Self::Input, _shared: &(), _params: &()) -> Result<u64> {
+            Ok(input.iter().sum())
+        }
+
+        fn solve_part_two(input: &Self::Input, _shared: &(), _params: &()) -> Result<u64> {
+            Ok(input.iter().sum())
+        }
+
+        fn part_one_alternatives() -> Vec<(&'static str, Solver<Self, u64>)> {
+            vec![("small", |input, _, _| {
+                if input.iter().any(|&n| n > 100) {
+                    Err(Unsupported("Too large".to_string()).into())
+                } else {
+                    Ok(input.iter().sum())
+                }
+            })]
+        }
+
+        fn part_two_alternatives() -> Vec<(&'static str, Solver<Self, u64>)> {
+            vec![("nonzero", |input, _, _| {
+                if input.contains(&0) {
+                    Err(anyhow!("Zero"))
+                } else {
+                    Ok(input.iter().sum())
+                }
+            })]
+        }
+    }
+
+    #[test]
+    fn unsupported_alternative_is_skipped() {
+        let solutions = solve_bytes::<Sum>(b"1\n2\n", &Options::default()).unwrap();
+        assert_eq!(solutions.part_one.unwrap().alternatives.len(), 1);
+
+        let solutions = solve_bytes::<Sum>(b"1\n200\n", &Options::default()).unwrap();
+        let part_one = solutions.part_one.unwrap();
+        assert_eq!(*part_one.result(), 201);
+        assert!(part_one.alternatives.is_empty());
+    }
+
+    #[test]
+    fn failing_alternative_is_an_error() {
+        let error = solve_bytes::<Sum>(b"0\n2\n", &Options::default())
+            .err()
+            .unwrap();
+        assert!(format!("{:#}", error).contains("Alternative solver 'nonzero' failed"));
+    }
+
+    #[test]
+    fn part_times_out_promptly() {
+        let start = Instant::now();
+        let solutions = solve_bytes::<SlowPart>(b"1\n2\n", &options()).unwrap();
+        assert!(start.elapsed() < SLEEP / 2);
+        assert!(solutions.part_one.is_err());
+        assert_eq!(*solutions.part_two.unwrap().result(), 3);
+    }
+
+    #[test]
+    fn prepare_times_out_promptly() {
+        let start = Instant::now();
+        let solutions = solve_bytes::<SlowPrepare>(b"1\n", &options()).unwrap();
+        assert!(start.elapsed() < SLEEP / 2);
+        assert!(solutions.part_one.is_err());
+        assert!(solutions.part_two.is_err());
+    }
 }
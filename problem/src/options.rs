@@ -0,0 +1,82 @@
+use crate::{Bench, Budget, Format};
+use anyhow::{anyhow, Context, Result};
+use std::time::Duration;
+
+#[derive(Clone, Debug, Default)]
+pub struct Options {
+    pub bench: Option<Bench>,
+    pub examples: bool,
+    pub format: Format,
+    pub parallel: bool,
+    pub params: Vec<(String, String)>,
+    pub record: bool,
+    pub timeout: Option<Duration>,
+}
+
+pub(crate) fn value<I: Iterator<Item = String>>(args: &mut I, flag: &str) -> Result<String> {
+    args.next()
+        .ok_or_else(|| anyhow!("Missing value for {}", flag))
+}
+
+pub(crate) fn seconds(value: &str) -> Result<Duration> {
+    value
+        .parse()
+        .ok()
+        .and_then(|seconds| Duration::try_from_secs_f64(seconds).ok())
+        .ok_or_else(|| anyhow!("Invalid number of seconds '{}'", value))
+}
+
+pub(crate) fn param(param: &str) -> Result<(String, String)> {
+    let (name, value) = param
+        .split_once('=')
+        .ok_or_else(|| anyhow!("Invalid parameter '{}', expected <name>=<value>", param))?;
+    Ok((name.to_string(), value.to_string()))
+}
+
+impl Options {
+    fn bench_mut(&mut self) -> &mut Bench {
+        self.bench.get_or_insert_with(Bench::default)
+    }
+
+    pub fn parse<I: IntoIterator<Item = String>>(args: I) -> Result<(Vec<String>, Self)> {
+        let mut positional = Vec::new();
+        let mut options = Self::default();
+
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--bench" => {
+                    options.bench_mut();
+                }
+                "--examples" => options.examples = true,
+                "--format" => options.format = value(&mut args, &arg)?.parse()?,
+                "--parallel" => options.parallel = true,
+                "--param" => options.params.push(param(&value(&mut args, &arg)?)?),
+                "--record" => options.record = true,
+                "--runs" => {
+                    let runs = value(&mut args, &arg)?;
+                    options.bench_mut().budget = Budget::Runs(
+                        runs.parse()
+                            .with_context(|| format!("Invalid number of runs '{}'", runs))?,
+                    );
+                }
+                "--timeout" => options.timeout = Some(seconds(&value(&mut args, &arg)?)?),
+                "--time" => {
+                    options.bench_mut().budget = Budget::Time(seconds(&value(&mut args, &arg)?)?);
+                }
+                "--warmup" => {
+                    options.bench_mut().warmup = seconds(&value(&mut args, &arg)?)?;
+                }
+                _ if arg.starts_with("--") => return Err(anyhow!("Unknown option '{}'", arg)),
+                _ => positional.push(arg),
+            }
+        }
+
+        // Concurrent parts and days contend with each other, so their timings aren't meaningful
+        if options.parallel && options.bench.is_some() {
+            return Err(anyhow!("--parallel cannot be used when benchmarking"));
+        }
+
+        Ok((positional, options))
+    }
+}
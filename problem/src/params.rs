@@ -0,0 +1,61 @@
+use anyhow::{anyhow, Result};
+
+pub trait Params: Default + Sync {
+    fn names() -> &'static [&'static str];
+    fn set(&mut self, name: &str, value: &str) -> Result<()>;
+}
+
+impl Params for () {
+    fn names() -> &'static [&'static str] {
+        &[]
+    }
+
+    fn set(&mut self, name: &str, _value: &str) -> Result<()> {
+        Err(anyhow!(
+            "Unknown parameter '{}', this day has no parameters",
+            name
+        ))
+    }
+}
+
+#[macro_export]
+macro_rules! params {
+    ($(#[$attr:meta])* $vis:vis struct $name:ident { $($field:ident: $ty:ty = $default:expr),* $(,)? }) => {
+        $(#[$attr])*
+        $vis struct $name {
+            $(pub $field: $ty,)*
+        }
+
+        impl ::core::default::Default for $name {
+            fn default() -> Self {
+                Self {
+                    $($field: $default,)*
+                }
+            }
+        }
+
+        impl $crate::Params for $name {
+            fn names() -> &'static [&'static str] {
+                &[$(stringify!($field)),*]
+            }
+
+            fn set(&mut self, name: &str, value: &str) -> ::core::result::Result<(), $crate::__private::Error> {
+                match name {
+                    $(stringify!($field) => {
+                        self.$field = value.parse().map_err(|e| {
+                            $crate::__private::anyhow!("Invalid value '{}' for parameter '{}': {}", value, name, e)
+                        })?;
+                    })*
+                    _ => {
+                        return Err($crate::__private::anyhow!(
+                            "Unknown parameter '{}', expected one of: {}",
+                            name,
+                            <Self as $crate::Params>::names().join(", ")
+                        ))
+                    }
+                }
+                Ok(())
+            }
+        }
+    };
+}
@@ -0,0 +1,300 @@
+use crate::ParseError;
+use anyhow::{anyhow, Error, Result};
+use std::{
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+    vec,
+};
+
+const FIELD: &str = "{}";
+
+struct Mismatch<'a> {
+    input: &'a str,
+    column: usize,
+    expected: Vec<&'a str>,
+    found: &'a str,
+}
+
+impl From<Mismatch<'_>> for Error {
+    fn from(mismatch: Mismatch<'_>) -> Self {
+        let expected = mismatch
+            .expected
+            .iter()
+            .map(|e| format!("'{}'", e))
+            .collect::<Vec<_>>();
+        let expected = match expected.as_slice() {
+            [] => "end of line".to_string(),
+            [one] => one.clone(),
+            many => format!("one of {}", many.join(", ")),
+        };
+        let error = if mismatch.found.is_empty() {
+            anyhow!("Expected {}, found end of line", expected)
+        } else {
+            anyhow!("Expected {}, found '{}'", expected, mismatch.found)
+        };
+        ParseError::in_text(mismatch.column + 1, mismatch.input, error).into()
+    }
+}
+
+pub struct Captures<'a> {
+    input: &'a str,
+    captures: vec::IntoIter<(usize, &'a str)>,
+}
+
+impl<'a> Captures<'a> {
+    // Errors are located at the start of the field, or within it if the field's own parser
+    // reports a column
+    pub fn next<T: FromStr>(&mut self, name: &str) -> Result<T>
+    where
+        T::Err: Into<Error>,
+    {
+        let (column, text) = self
+            .captures
+            .next()
+            .ok_or_else(|| anyhow!("Pattern has no field for {}", name))?;
+        text.parse().map_err(|e| {
+            let parse_error = match Into::<Error>::into(e).downcast::<ParseError>() {
+                Ok(ParseError {
+                    column: Some(offset),
+                    error,
+                    ..
+                }) => ParseError::in_text(column + offset, self.input, error),
+                Ok(parse_error) => ParseError::in_text(column + 1, self.input, parse_error.error),
+                Err(e) => ParseError::in_text(
+                    column + 1,
+                    self.input,
+                    anyhow!("Invalid {} '{}': {:#}", name, text, e),
+                ),
+            };
+            parse_error.into()
+        })
+    }
+}
+
+fn expected<'a>(input: &'a str, column: usize, literal: &'a str) -> Mismatch<'a> {
+    Mismatch {
+        input,
+        column,
+        expected: vec![literal],
+        found: &input[column..],
+    }
+}
+
+fn try_scan<'a>(pattern: &'a str, input: &'a str) -> Result<Vec<(usize, &'a str)>, Mismatch<'a>> {
+    let mut literals = pattern.split(FIELD);
+    let first = literals.next().unwrap_or_default();
+    if !input.starts_with(first) {
+        return Err(expected(input, 0, first));
+    }
+
+    let mut column = first.len();
+    let mut captures = Vec::new();
+    for literal in literals {
+        if literal.is_empty() {
+            captures.push((column, &input[column..]));
+            column = input.len();
+        } else {
+            let length = input[column..]
+                .find(literal)
+                .ok_or_else(|| expected(input, column, literal))?;
+            captures.push((column, &input[column..column + length]));
+            column += length + literal.len();
+        }
+    }
+
+    if column != input.len() {
+        return Err(Mismatch {
+            input,
+            column,
+            expected: Vec::new(),
+            found: &input[column..],
+        });
+    }
+
+    Ok(captures)
+}
+
+pub fn scan<'a>(pattern: &'a str, input: &'a str) -> Result<Captures<'a>> {
+    Ok(Captures {
+        input,
+        captures: try_scan(pattern, input)?.into_iter(),
+    })
+}
+
+pub fn scan_any<'a>(patterns: &[&'a str], input: &'a str) -> Result<(usize, Captures<'a>)> {
+    let mut furthest: Option<Mismatch> = None;
+    for (i, pattern) in patterns.iter().enumerate() {
+        match try_scan(pattern, input) {
+            Ok(captures) => {
+                return Ok((
+                    i,
+                    Captures {
+                        input,
+                        captures: captures.into_iter(),
+                    },
+                ))
+            }
+            Err(mut mismatch) => match &mut furthest {
+                Some(f) if f.column == mismatch.column => f.expected.append(&mut mismatch.expected),
+                Some(f) if f.column > mismatch.column => (),
+                _ => furthest = Some(mismatch),
+            },
+        }
+    }
+
+    Err(furthest.map_or_else(|| anyhow!("No patterns to match"), Error::from))
+}
+
+pub fn unscan(f: &mut Formatter<'_>, pattern: &str, fields: &[&dyn Display]) -> fmt::Result {
+    let mut literals = pattern.split(FIELD);
+    f.write_str(literals.next().unwrap_or_default())?;
+    for (literal, field) in literals.zip(fields) {
+        write!(f, "{}{}", field, literal)?;
+    }
+    Ok(())
+}
+
+#[macro_export]
+macro_rules! pattern {
+    (struct $ty:ident = $pattern:literal { $($field:ident),* $(,)? }) => {
+        impl ::core::str::FromStr for $ty {
+            type Err = $crate::__private::Error;
+
+            fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+                #[allow(unused_mut, unused_variables)]
+                let mut captures = $crate::scan($pattern, s)?;
+                Ok(Self {
+                    $($field: captures.next(stringify!($field))?,)*
+                })
+            }
+        }
+
+        impl ::core::fmt::Display for $ty {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                $crate::unscan(f, $pattern, &[$(&self.$field),*])
+            }
+        }
+    };
+    (enum $ty:ident { $($variant:ident $(($($field:ident),*))? = $pattern:literal),* $(,)? }) => {
+        impl ::core::str::FromStr for $ty {
+            type Err = $crate::__private::Error;
+
+            fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+                #[allow(unused_mut, unused_variables)]
+                let (index, mut captures) = $crate::scan_any(&[$($pattern),*], s)?;
+                let mut _variant = 0;
+                $(
+                    if index == _variant {
+                        return Ok(Self::$variant $(($(captures.next(stringify!($field))?),*))?);
+                    }
+                    _variant += 1;
+                )*
+                unreachable!()
+            }
+        }
+
+        impl ::core::fmt::Display for $ty {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                match self {
+                    $(Self::$variant $(($($field),*))? => $crate::unscan(f, $pattern, &[$($($field),*)?]),)*
+                }
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{scan, scan_any, Input, ParseError};
+    use anyhow::Error;
+
+    #[derive(Debug)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    pattern!(struct Point = "{},{}" { x, y });
+
+    #[derive(Debug)]
+    struct Line {
+        from: Point,
+        to: Point,
+    }
+
+    pattern!(struct Line = "{} -> {}" { from, to });
+
+    fn located(error: Error) -> (Option<usize>, String) {
+        let parse_error = ParseError::find(&error).unwrap();
+        (parse_error.column, parse_error.error.to_string())
+    }
+
+    #[test]
+    fn captures() {
+        let mut captures = scan("move {} from {} to {}", "move 3 from 1 to 20").unwrap();
+        assert_eq!(captures.next::<u32>("count").unwrap(), 3);
+        assert_eq!(captures.next::<u32>("from").unwrap(), 1);
+        assert_eq!(captures.next::<u32>("to").unwrap(), 20);
+    }
+
+    #[test]
+    fn literal_mismatch() {
+        let error = scan("move {} from {}", "move 3 to 1").err().unwrap();
+        assert_eq!(
+            located(error),
+            (Some(6), "Expected ' from ', found '3 to 1'".to_string())
+        );
+        let error = scan("({},{})", "(1,2) ").err().unwrap();
+        assert_eq!(
+            located(error),
+            (Some(6), "Expected end of line, found ' '".to_string())
+        );
+        let error = scan("{} -> {}", "").err().unwrap();
+        assert_eq!(
+            located(error),
+            (Some(1), "Expected ' -> ', found end of line".to_string())
+        );
+    }
+
+    #[test]
+    fn capture_mismatch() {
+        let error = "1,-x".parse::<Point>().unwrap_err();
+        assert_eq!(
+            located(error),
+            (
+                Some(3),
+                "Invalid y '-x': invalid digit found in string".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn scan_any_match() {
+        let (index, mut captures) = scan_any(&["on {}", "off {}"], "off 5").unwrap();
+        assert_eq!(index, 1);
+        assert_eq!(captures.next::<u32>("value").unwrap(), 5);
+    }
+
+    #[test]
+    fn scan_any_merges_furthest_mismatches() {
+        let error = scan_any(&["x{}", "a{}b", "a{}c"], "axd").err().unwrap();
+        assert_eq!(
+            located(error),
+            (Some(2), "Expected one of 'b', 'c', found 'xd'".to_string())
+        );
+    }
+
+    #[test]
+    fn caret_at_nested_capture() {
+        let error = Vec::<Line>::parse("0,9 -> 5,9\n8,0 -> 0,x\n".as_bytes()).unwrap_err();
+        let parse_error = ParseError::find(&error).unwrap();
+        assert_eq!(
+            parse_error.to_string(),
+            "Failed to parse line 2, column 10: Invalid y 'x': invalid digit found in string"
+        );
+        assert_eq!(
+            parse_error.snippet(),
+            "  |\n2 | 8,0 -> 0,x\n  |          ^\n"
+        );
+    }
+}
@@ -0,0 +1,283 @@
+use crate::{
+    error, history, inputs,
+    options::{param, seconds, value},
+    report, scaffold, visualize, Ansi, Format, Options, ParseError, Ppm, Problem, Renderer, Report,
+    Visualize,
+};
+use anyhow::{anyhow, Context, Result};
+use rayon::prelude::*;
+use std::{
+    env,
+    path::{Path, PathBuf},
+    process,
+    time::{Duration, Instant},
+};
+
+const USAGE: &str = "\
+Usage:
+  aoc run [options] <day> [input | -]
+  aoc run [options] --all [directory]
+  aoc visualize [options] <day> [input | -]
+  aoc compare [day]
+  aoc new <day>
+
+Options:
+  --bench              Benchmark each part and save the timings to the history
+  --delay <seconds>    Wait between frames when visualizing in the terminal
+  --examples           Run the day's examples instead of its puzzle input
+  --format <format>    Print results as 'text', 'json' or 'csv'
+  --param <name=value> Override one of the day's puzzle parameters
+  --parallel           Run independent parts and days concurrently
+  --ppm <directory>    Write visualization frames to a directory as PPM images
+  --record             Record the solutions as the expected answers
+  --runs <count>       Benchmark each part for a number of runs
+  --time <seconds>     Benchmark each part for a length of time
+  --timeout <seconds>  Report a part as TIMEOUT if it runs longer than this
+  --warmup <seconds>   Run each part for a length of time before benchmarking";
+
+type VisualizeFn = fn(&Path, &[(String, String)], &mut dyn Renderer) -> Result<()>;
+
+pub struct Day {
+    number: usize,
+    report: fn(&Path, &Options) -> Result<Report>,
+    visualize: Option<VisualizeFn>,
+}
+
+impl Day {
+    pub fn of<P: Problem>() -> Self {
+        Self {
+            number: P::DAY,
+            report: report::<P>,
+            visualize: None,
+        }
+    }
+
+    pub fn visualized<P: Visualize>() -> Self {
+        Self {
+            visualize: Some(visualize::visualize::<P>),
+            ..Self::of::<P>()
+        }
+    }
+
+    pub fn number(&self) -> usize {
+        self.number
+    }
+
+    pub fn report(&self, path: &Path, options: &Options) -> Result<Report> {
+        (self.report)(path, options)
+    }
+}
+
+fn find<'a>(days: &'a [Day], day: &str) -> Result<&'a Day> {
+    let number = day
+        .parse::<usize>()
+        .with_context(|| format!("Invalid day '{}'", day))?;
+    days.iter()
+        .find(|d| d.number == number)
+        .ok_or_else(|| anyhow!("Day {} is not registered", number))
+}
+
+pub(crate) fn run_one(day: &Day, path: Option<&str>, options: &Options) -> Result<()> {
+    let paths = inputs::resolve(day.number, path, options.examples)?;
+
+    let mut reports = Vec::new();
+    for path in paths.iter() {
+        let report = day.report(path, options)?;
+        if paths.len() > 1 && options.format == Format::Text {
+            println!("{}:", path.display());
+            println!("{}", report);
+        }
+        reports.push(report);
+    }
+    match reports.as_slice() {
+        [report] => options.format.print_report(report)?,
+        _ if options.format == Format::Text => (),
+        reports => options.format.print_reports(reports)?,
+    }
+    if options.bench.is_some() {
+        history::save(&paths, &reports, &options.params)?;
+    }
+
+    if reports.iter().any(Report::is_fail) {
+        Err(anyhow!(
+            "Day {} does not match the expected answers",
+            day.number
+        ))
+    } else if reports.iter().any(Report::is_timeout) {
+        Err(anyhow!("Day {} did not finish in time", day.number))
+    } else {
+        Ok(())
+    }
+}
+
+fn run_all(days: &[Day], directory: &Path, options: &Options) -> Result<()> {
+    let mut days = days.iter().collect::<Vec<_>>();
+    days.sort_by_key(|d| d.number);
+
+    let mut found = Vec::new();
+    for day in days {
+        let path = inputs::day_dir(directory, day.number).join("input.txt");
+        if path.exists() {
+            found.push((day, path));
+        } else {
+            eprintln!(
+                "Day {}: skipped, no input at {}",
+                day.number,
+                path.display()
+            );
+        }
+    }
+
+    let start = Instant::now();
+    let results = if options.parallel {
+        found
+            .par_iter()
+            .map(|(day, path)| day.report(path, options))
+            .collect::<Vec<_>>()
+    } else {
+        found
+            .iter()
+            .map(|(day, path)| {
+                let result = day.report(path, options);
+                if let (Ok(report), Format::Text) = (&result, options.format) {
+                    println!("{}", report);
+                }
+                result
+            })
+            .collect::<Vec<_>>()
+    };
+    let wall = Instant::now().duration_since(start);
+
+    let mut reports = Vec::new();
+    let mut paths = Vec::new();
+    let mut failures = 0;
+    for ((day, path), result) in found.iter().zip(results) {
+        match result {
+            Ok(report) => {
+                if options.parallel && options.format == Format::Text {
+                    println!("{}", report);
+                }
+                if report.is_fail() || report.is_timeout() {
+                    failures += 1;
+                }
+                reports.push(report);
+                paths.push(path.clone());
+            }
+            Err(e) => {
+                eprintln!("Day {}: failed: {:#}", day.number, e);
+                if let Some(parse_error) = ParseError::find(&e) {
+                    eprint!("{}", parse_error.snippet());
+                }
+                failures += 1;
+            }
+        }
+    }
+
+    if options.bench.is_some() {
+        history::save(&paths, &reports, &options.params)?;
+    }
+
+    if options.format == Format::Text {
+        println!("Summary:");
+        for report in reports.iter() {
+            println!(
+                "  Day {:>2}: {} seconds ({} / {})",
+                report.day,
+                report.duration().as_secs_f64(),
+                report.part_one.status.name(),
+                report.part_two.status.name(),
+            );
+        }
+        let total = reports.iter().map(|r| r.duration()).sum::<Duration>();
+        println!("  Total:  {} seconds", total.as_secs_f64());
+        println!("  Wall:   {} seconds", wall.as_secs_f64());
+    } else {
+        options.format.print_reports(&reports)?;
+    }
+
+    if failures > 0 {
+        Err(anyhow!("{} day(s) failed", failures))
+    } else {
+        Ok(())
+    }
+}
+
+fn run_visualize(days: &[Day], args: &[String]) -> Result<()> {
+    let mut delay = Duration::from_millis(100);
+    let mut ppm = None;
+    let mut params = Vec::new();
+    let mut positional = Vec::new();
+
+    let mut args = args.iter().cloned();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--delay" => delay = seconds(&value(&mut args, &arg)?)?,
+            "--ppm" => ppm = Some(PathBuf::from(value(&mut args, &arg)?)),
+            "--param" => params.push(param(&value(&mut args, &arg)?)?),
+            _ if arg.starts_with("--") => return Err(anyhow!("Unknown option '{}'", arg)),
+            _ => positional.push(arg),
+        }
+    }
+
+    let (day, path) = match positional.as_slice() {
+        [day] => (find(days, day)?, None),
+        [day, path] => (find(days, day)?, Some(path.as_str())),
+        _ => return Err(anyhow!(USAGE)),
+    };
+    let visualize = day
+        .visualize
+        .ok_or_else(|| anyhow!("Day {} does not support visualization", day.number))?;
+    let path = inputs::resolve(day.number, path, false)?.remove(0);
+
+    let mut renderer: Box<dyn Renderer> = match ppm {
+        Some(directory) => Box::new(Ppm::new(&directory)?),
+        None => Box::new(Ansi::new(delay)),
+    };
+    visualize(&path, &params, renderer.as_mut())
+}
+
+fn run(days: &[Day], args: &[String]) -> Result<()> {
+    match args {
+        [command, rest @ ..] if command == "run" => {
+            let all = rest.iter().any(|arg| arg == "--all");
+            let (rest, options) =
+                Options::parse(rest.iter().filter(|&arg| arg != "--all").cloned())?;
+            if all && !options.params.is_empty() {
+                return Err(anyhow!(
+                    "--param can only be used when running a single day"
+                ));
+            }
+            if all && options.examples {
+                return Err(anyhow!(
+                    "--examples can only be used when running a single day"
+                ));
+            }
+            match rest.as_slice() {
+                [] if all => run_all(days, &inputs::inputs_dir()?, &options),
+                [directory] if all => run_all(days, directory.as_ref(), &options),
+                [day] if !all => run_one(find(days, day)?, None, &options),
+                [day, path] if !all => run_one(find(days, day)?, Some(path), &options),
+                _ => Err(anyhow!(USAGE)),
+            }
+        }
+        [command, rest @ ..] if command == "visualize" => run_visualize(days, rest),
+        [command] if command == "compare" => history::compare(None),
+        [command, day] if command == "compare" => history::compare(Some(
+            day.parse()
+                .with_context(|| format!("Invalid day '{}'", day))?,
+        )),
+        [command, day] if command == "new" => scaffold::new_day(
+            day.parse()
+                .with_context(|| format!("Invalid day '{}'", day))?,
+        ),
+        _ => Err(anyhow!(USAGE)),
+    }
+}
+
+pub fn run_main(days: &[Day]) {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    if let Err(e) = run(days, &args) {
+        error::print_error(&e);
+        process::exit(1);
+    }
+}
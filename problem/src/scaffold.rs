@@ -0,0 +1,107 @@
+use crate::inputs::{day_dir, workspace_dir};
+use anyhow::{anyhow, Context, Result};
+use std::{fs, path::Path};
+
+fn day_number(line: &str) -> Option<usize> {
+    let start = line.find("day_")? + 4;
+    let digits = line[start..]
+        .chars()
+        .take_while(char::is_ascii_digit)
+        .collect::<String>();
+    digits.parse().ok()
+}
+
+// Inserts a line into the block of days that follows the marker, keeping the days in order
+fn register(path: &Path, marker: &str, line: &str, day: usize) -> Result<()> {
+    let text =
+        fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))?;
+    let mut lines = text.lines().collect::<Vec<_>>();
+    let start = lines
+        .iter()
+        .position(|l| l.trim() == marker)
+        .ok_or_else(|| anyhow!("Could not find '{}' in {}", marker, path.display()))?;
+    let block = start
+        + 1
+        + lines[start + 1..]
+            .iter()
+            .take_while(|l| l.is_empty())
+            .count();
+    let index = lines[block..]
+        .iter()
+        .position(|l| l.is_empty() || day_number(l).is_none_or(|n| n > day))
+        .map_or(lines.len(), |i| block + i);
+    lines.insert(index, line);
+
+    let mut text = lines.join("\n");
+    text.push('\n');
+    fs::write(path, text).with_context(|| format!("Failed to write {}", path.display()))
+}
+
+fn instantiate(from: &Path, to: &Path, day: usize) -> Result<()> {
+    fs::create_dir_all(to)?;
+    for entry in fs::read_dir(from)? {
+        let entry = entry?;
+        let target = to.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            instantiate(&entry.path(), &target, day)?;
+        } else {
+            let text = fs::read_to_string(entry.path())?
+                .replace("template", &format!("day_{}", day))
+                .replace("DayN", &format!("Day{}", day))
+                .replace("DAY: usize = 0", &format!("DAY: usize = {}", day));
+            fs::write(&target, text)
+                .with_context(|| format!("Failed to write {}", target.display()))?;
+        }
+    }
+    Ok(())
+}
+
+pub fn new_day(day: usize) -> Result<()> {
+    if day == 0 {
+        return Err(anyhow!("Day numbers start at 1"));
+    }
+
+    let workspace = workspace_dir()?;
+    let name = format!("day_{}", day);
+    let directory = workspace.join(&name);
+    let manifest = workspace.join("Cargo.toml");
+    if directory.exists() {
+        return Err(anyhow!("{} already exists", directory.display()));
+    }
+    if fs::read_to_string(&manifest)?.contains(&format!("\"{}\"", name)) {
+        return Err(anyhow!("{} is already a workspace member", name));
+    }
+
+    instantiate(&workspace.join("template"), &directory, day)?;
+    register(&manifest, "members = [", &format!("    \"{}\",", name), day)?;
+    register(
+        &workspace.join("days").join("Cargo.toml"),
+        "[dependencies]",
+        &format!("{} = {{ path = \"../{}\" }}", name, name),
+        day,
+    )?;
+    register(
+        &workspace.join("days").join("src").join("lib.rs"),
+        "//! Re-exports every day's solution by number",
+        &format!("pub use {}::{{self, Day{}}};", name, day),
+        day,
+    )?;
+    register(
+        &workspace.join("days").join("src").join("lib.rs"),
+        "vec![",
+        &format!("        problem::Day::of::<{}::Day{}>(),", name, day),
+        day,
+    )?;
+
+    let inputs = day_dir(&workspace.join("inputs"), day);
+    fs::create_dir_all(&inputs)?;
+    for file in ["input.txt", "example_1.txt"] {
+        let path = inputs.join(file);
+        if !path.exists() {
+            fs::write(&path, "")?;
+        }
+    }
+
+    eprintln!("Created {} at {}", name, directory.display());
+    Ok(())
+}
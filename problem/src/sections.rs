@@ -0,0 +1,163 @@
+use crate::{Input, ParseError};
+use anyhow::{anyhow, Context, Result};
+use std::{io::BufRead, ops::Range};
+
+struct Split {
+    lines: Vec<String>,
+    sections: Vec<Range<usize>>,
+}
+
+impl Split {
+    fn read<R: BufRead>(reader: R) -> Result<Self> {
+        let lines = reader
+            .lines()
+            .collect::<Result<Vec<_>, _>>()
+            .context("Failed to read line")?;
+
+        let mut sections = Vec::new();
+        let mut start = None;
+        for (i, line) in lines.iter().enumerate() {
+            match (line.trim().is_empty(), start) {
+                (true, Some(s)) => {
+                    sections.push(s..i);
+                    start = None;
+                }
+                (false, None) => start = Some(i),
+                _ => (),
+            }
+        }
+        if let Some(s) = start {
+            sections.push(s..lines.len());
+        }
+
+        Ok(Self { lines, sections })
+    }
+
+    fn parse<T: Input>(&self, index: usize, lines: Range<usize>) -> Result<T> {
+        let text = self.lines[lines.clone()].join("\n");
+        T::parse(text.as_bytes())
+            .map_err(|mut e| {
+                if let Some(line) = e
+                    .downcast_mut::<ParseError>()
+                    .and_then(|parse_error| parse_error.line.as_mut())
+                {
+                    *line += lines.start;
+                }
+                e
+            })
+            .with_context(|| {
+                format!(
+                    "Failed to parse section {} starting on line {}",
+                    index + 1,
+                    lines.start + 1
+                )
+            })
+    }
+
+    fn parse_section<T: Input>(&self, index: usize) -> Result<T> {
+        let lines = self.sections.get(index).cloned().ok_or_else(|| {
+            anyhow!(
+                "Missing section {}, found only {}",
+                index + 1,
+                self.sections.len()
+            )
+        })?;
+        self.parse(index, lines)
+    }
+
+    fn parse_rest<T: Input>(&self, index: usize) -> Result<T> {
+        let start = self
+            .sections
+            .get(index)
+            .map_or(self.lines.len(), |s| s.start);
+        self.parse(index, start..self.lines.len())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Sections<T>(Vec<T>);
+
+impl<T> Sections<T> {
+    pub fn values(&self) -> &[T] {
+        &self.0
+    }
+
+    pub fn into_values(self) -> Vec<T> {
+        self.0
+    }
+}
+
+impl<T> From<Vec<T>> for Sections<T> {
+    fn from(values: Vec<T>) -> Self {
+        Self(values)
+    }
+}
+
+impl<T: Input> Input for Sections<T> {
+    fn parse<R: BufRead>(reader: R) -> Result<Self> {
+        let split = Split::read(reader)?;
+        (0..split.sections.len())
+            .map(|i| split.parse_section(i))
+            .collect::<Result<Vec<_>>>()
+            .map(Self)
+    }
+}
+
+macro_rules! impl_tuple {
+    ($($first:ident: $i:tt),*; $last:ident: $n:tt) => {
+        impl<$($first: Input,)* $last: Input> Input for ($($first,)* $last,) {
+            fn parse<R: BufRead>(reader: R) -> Result<Self> {
+                let split = Split::read(reader)?;
+                Ok(($(split.parse_section::<$first>($i)?,)* split.parse_rest::<$last>($n)?,))
+            }
+        }
+    };
+}
+
+impl_tuple!(A: 0; B: 1);
+impl_tuple!(A: 0, B: 1; C: 2);
+impl_tuple!(A: 0, B: 1, C: 2; D: 3);
+
+#[cfg(test)]
+mod tests {
+    use super::Sections;
+    use crate::{Input, ParseError};
+
+    #[test]
+    fn split_on_blank_lines() {
+        let sections = Sections::<Vec<u32>>::parse("1\n2\n\n3\n  \n4\n5".as_bytes()).unwrap();
+        assert_eq!(sections.values(), [vec![1, 2], vec![3], vec![4, 5]]);
+    }
+
+    #[test]
+    fn ignore_extra_blank_lines() {
+        let sections = Sections::<Vec<u32>>::parse("\n1\n\n\n2\n\n\n".as_bytes()).unwrap();
+        assert_eq!(sections.values(), [vec![1], vec![2]]);
+    }
+
+    #[test]
+    fn tuple() {
+        let (first, rest) =
+            <(Vec<u32>, Vec<String>)>::parse("1\n2\n\na\n\nb\n".as_bytes()).unwrap();
+        assert_eq!(first, [1, 2]);
+        assert_eq!(rest, ["a", "", "b"]);
+    }
+
+    #[test]
+    fn missing_section() {
+        let error = <(Vec<u32>, Vec<u32>, Vec<u32>)>::parse("1\n".as_bytes()).unwrap_err();
+        assert_eq!(error.to_string(), "Missing section 2, found only 1");
+    }
+
+    #[test]
+    fn error_line_offset() {
+        let error = Sections::<Vec<u32>>::parse("1\n2\n\n3\nx\n".as_bytes()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Failed to parse section 2 starting on line 4"
+        );
+        let parse_error = ParseError::find(&error).unwrap();
+        assert_eq!(parse_error.line, Some(5));
+        assert_eq!(parse_error.text, "x");
+    }
+}
@@ -0,0 +1,147 @@
+use crate::{solve_str, Input, Problem, Solver, Unparse, Unsupported};
+use anyhow::Result;
+use std::fmt::Display;
+
+pub fn check_example<P: Problem>(input: &str, part_one: Option<String>, part_two: Option<String>) {
+    let solutions = solve_str::<P>(input)
+        .unwrap_or_else(|e| panic!("Failed to solve day {} example: {:#}", P::DAY, e));
+
+    if let Some(expected) = part_one {
+        assert_eq!(
+            solutions
+                .part_one
+                .as_ref()
+                .unwrap_or_else(|e| panic!("Day {} part one: {}", P::DAY, e))
+                .result()
+                .to_string()
+                .trim(),
+            expected.trim(),
+            "Day {} part one",
+            P::DAY
+        );
+    }
+    if let Some(expected) = part_two {
+        assert_eq!(
+            solutions
+                .part_two
+                .as_ref()
+                .unwrap_or_else(|e| panic!("Day {} part two: {}", P::DAY, e))
+                .result()
+                .to_string()
+                .trim(),
+            expected.trim(),
+            "Day {} part two",
+            P::DAY
+        );
+    }
+}
+
+pub fn check_round_trip<T: Input + Unparse>(value: &T) {
+    let text = value.unparse_to_string();
+    let parsed = T::parse(text.as_bytes())
+        .unwrap_or_else(|e| panic!("Failed to parse unparsed input: {:#}\n{}", e, text));
+    assert_eq!(parsed.unparse_to_string(), text);
+}
+
+fn check_alternatives<P: Problem, T: Display>(
+    part: &str,
+    expected: Result<T>,
+    alternatives: Vec<(&'static str, Solver<P, T>)>,
+    input: &P::Input,
+    shared: &P::Shared,
+    params: &P::Params,
+) {
+    let expected = match expected {
+        Ok(expected) => expected.to_string(),
+        Err(_) => return,
+    };
+    for (name, alternative) in alternatives {
+        let answer = match alternative(input, shared, params) {
+            Ok(answer) => answer.to_string(),
+            Err(e) if e.is::<Unsupported>() => continue,
+            Err(e) => panic!(
+                "Day {} part {} alternative '{}' failed: {:#}",
+                P::DAY,
+                part,
+                name,
+                e
+            ),
+        };
+        assert_eq!(
+            answer.trim(),
+            expected.trim(),
+            "Day {} part {} alternative '{}'",
+            P::DAY,
+            part,
+            name
+        );
+    }
+}
+
+// Solvers may reject generated inputs with an error, but must not panic or disagree
+pub fn check_solvers<P: Problem>(input: &P::Input) {
+    let params = P::Params::default();
+    if let Ok(shared) = P::prepare(input, &params) {
+        check_alternatives::<P, _>(
+            "one",
+            P::solve_part_one(input, &shared, &params),
+            P::part_one_alternatives(),
+            input,
+            &shared,
+            &params,
+        );
+        check_alternatives::<P, _>(
+            "two",
+            P::solve_part_two(input, &shared, &params),
+            P::part_two_alternatives(),
+            input,
+            &shared,
+            &params,
+        );
+    }
+}
+
+#[cfg(feature = "proptest")]
+pub fn grid<T: Clone + std::fmt::Debug, S: proptest::strategy::Strategy<Value = T> + Clone>(
+    cell: S,
+    width: std::ops::Range<usize>,
+    height: std::ops::Range<usize>,
+) -> impl proptest::strategy::Strategy<Value = crate::Grid<T>> {
+    use proptest::{collection::vec, strategy::Strategy};
+
+    (width, height).prop_flat_map(move |(width, height)| {
+        vec(cell.clone(), width * height)
+            .prop_map(move |cells| crate::Grid::from_cells(width, height, cells).unwrap())
+    })
+}
+
+#[macro_export]
+macro_rules! example_test {
+    ($name:ident: $problem:ty, input = $input:expr $(, part_one = $one:expr)? $(, part_two = $two:expr)? $(,)?) => {
+        #[test]
+        fn $name() {
+            $crate::testing::check_example::<$problem>(
+                $input,
+                None $(.or(Some($one.to_string())))?,
+                None $(.or(Some($two.to_string())))?,
+            );
+        }
+    };
+    ($name:ident: $problem:ty, $file:literal $(, part_one = $one:expr)? $(, part_two = $two:expr)? $(,)?) => {
+        $crate::example_test!(
+            $name: $problem,
+            input = include_str!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/../inputs/",
+                env!("CARGO_PKG_NAME"),
+                "/",
+                $file
+            ))
+            $(, part_one = $one)?
+            $(, part_two = $two)?
+        );
+    };
+    ($problem:ty, $($rest:tt)*) => {
+        $crate::example_test!(example: $problem, $($rest)*);
+    };
+}
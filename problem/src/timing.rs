@@ -0,0 +1,184 @@
+use crate::alloc::{self, Allocations};
+use std::{
+    fmt::{self, Display},
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+#[derive(Clone, Copy, Debug)]
+pub enum Budget {
+    Runs(usize),
+    Time(Duration),
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Bench {
+    pub warmup: Duration,
+    pub budget: Budget,
+}
+
+impl Default for Bench {
+    fn default() -> Self {
+        Self {
+            warmup: Duration::from_millis(500),
+            budget: Budget::Time(Duration::from_secs(3)),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Timing {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+    pub allocations: Option<Allocations>,
+}
+
+impl Timing {
+    pub(crate) fn from_samples(
+        mut samples: Vec<Duration>,
+        allocations: Option<Allocations>,
+    ) -> Self {
+        samples.sort_unstable();
+
+        let runs = samples.len();
+        let median = if runs.is_multiple_of(2) {
+            (samples[runs / 2 - 1] + samples[runs / 2]) / 2
+        } else {
+            samples[runs / 2]
+        };
+        let mean = samples.iter().map(|s| s.as_secs_f64()).sum::<f64>() / runs as f64;
+        let variance = if runs > 1 {
+            samples
+                .iter()
+                .map(|s| (s.as_secs_f64() - mean).powi(2))
+                .sum::<f64>()
+                / (runs - 1) as f64
+        } else {
+            0.0
+        };
+
+        Self {
+            runs,
+            min: samples[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+            allocations,
+        }
+    }
+}
+
+impl Display for Timing {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.runs == 1 {
+            writeln!(f, "  Elapsed:  {} seconds", self.median.as_secs_f64())?;
+        } else {
+            writeln!(
+                f,
+                "  Elapsed:  {} seconds (median of {} runs)",
+                self.median.as_secs_f64(),
+                self.runs
+            )?;
+            writeln!(f, "  Min:      {} seconds", self.min.as_secs_f64())?;
+            writeln!(f, "  Mean:     {} seconds", self.mean.as_secs_f64())?;
+            writeln!(f, "  Stddev:   {} seconds", self.stddev.as_secs_f64())?;
+        }
+        if let Some(allocations) = self.allocations {
+            writeln!(
+                f,
+                "  Allocs:   {} ({} bytes, peak {} bytes)",
+                allocations.count, allocations.bytes, allocations.peak
+            )?;
+        }
+        Ok(())
+    }
+}
+
+fn sample<F: FnMut() -> T, T>(f: &mut F) -> (T, Duration) {
+    let start = Instant::now();
+    let result = black_box(f());
+    (result, Instant::now().duration_since(start))
+}
+
+pub fn time<F: FnOnce() -> T, T>(f: F) -> (T, Timing) {
+    let ((result, duration), allocations) = alloc::measure(|| {
+        let start = Instant::now();
+        let result = f();
+        (result, Instant::now().duration_since(start))
+    });
+    (result, Timing::from_samples(vec![duration], allocations))
+}
+
+pub fn bench<F: FnMut() -> T, T>(bench: &Bench, mut f: F) -> (T, Timing) {
+    let warmup_start = Instant::now();
+    while Instant::now().duration_since(warmup_start) < bench.warmup {
+        sample(&mut f);
+    }
+
+    // Allocations are measured on a separate run so that counting doesn't skew the timings
+    let (_, allocations) = alloc::measure(|| black_box(f()));
+
+    let start = Instant::now();
+    let (mut result, duration) = sample(&mut f);
+    let mut samples = vec![duration];
+    loop {
+        let finished = match bench.budget {
+            Budget::Runs(runs) => samples.len() >= runs,
+            Budget::Time(time) => Instant::now().duration_since(start) >= time,
+        };
+        if finished {
+            break;
+        }
+
+        let (next, duration) = sample(&mut f);
+        result = next;
+        samples.push(duration);
+    }
+
+    (result, Timing::from_samples(samples, allocations))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Timing;
+    use std::time::Duration;
+
+    fn millis(samples: &[u64]) -> Vec<Duration> {
+        samples.iter().copied().map(Duration::from_millis).collect()
+    }
+
+    #[test]
+    fn odd_median() {
+        let timing = Timing::from_samples(millis(&[5, 1, 3]), None);
+        assert_eq!(timing.runs, 3);
+        assert_eq!(timing.min, Duration::from_millis(1));
+        assert_eq!(timing.median, Duration::from_millis(3));
+    }
+
+    #[test]
+    fn even_median() {
+        let timing = Timing::from_samples(millis(&[4, 1, 2, 8]), None);
+        assert_eq!(timing.median, Duration::from_millis(3));
+    }
+
+    #[test]
+    fn single_run() {
+        let timing = Timing::from_samples(millis(&[7]), None);
+        assert_eq!(timing.runs, 1);
+        assert_eq!(timing.median, Duration::from_millis(7));
+        assert_eq!(timing.mean, Duration::from_millis(7));
+        assert_eq!(timing.stddev, Duration::ZERO);
+    }
+
+    #[test]
+    fn sample_stddev() {
+        // Squared deviations from the mean of 5ms sum to 32ms^2, over 8 - 1 degrees of freedom
+        let timing = Timing::from_samples(millis(&[2, 4, 4, 4, 5, 5, 7, 9]), None);
+        assert!((timing.mean.as_secs_f64() - 0.005).abs() < 1e-12);
+        let expected = (32.0f64 / 7.0).sqrt() / 1000.0;
+        assert!((timing.stddev.as_secs_f64() - expected).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,74 @@
+use crate::{Cell, Grid, Sections, CSV};
+use std::{
+    fmt::Display,
+    io::{self, Write},
+};
+
+pub trait Unparse {
+    fn unparse<W: Write>(&self, writer: &mut W) -> io::Result<()>;
+
+    fn unparse_to_string(&self) -> String {
+        let mut bytes = Vec::new();
+        self.unparse(&mut bytes)
+            .expect("Writing to a Vec<u8> cannot fail");
+        String::from_utf8(bytes).expect("Unparsed input is not valid UTF-8")
+    }
+}
+
+impl<T: Display> Unparse for Vec<T> {
+    fn unparse<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        for value in self.iter() {
+            writeln!(writer, "{}", value)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: Display> Unparse for CSV<T> {
+    fn unparse<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        for (i, value) in self.values().iter().enumerate() {
+            if i != 0 {
+                write!(writer, ",")?;
+            }
+            write!(writer, "{}", value)?;
+        }
+        writeln!(writer)
+    }
+}
+
+impl<T: Cell> Unparse for Grid<T> {
+    fn unparse<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        write!(writer, "{}", self)
+    }
+}
+
+impl<T: Unparse> Unparse for Sections<T> {
+    fn unparse<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        for (i, section) in self.values().iter().enumerate() {
+            if i != 0 {
+                writeln!(writer)?;
+            }
+            section.unparse(writer)?;
+        }
+        Ok(())
+    }
+}
+
+macro_rules! impl_tuple {
+    ($first:ident: $i:tt $(, $rest:ident: $j:tt)*) => {
+        impl<$first: Unparse, $($rest: Unparse),*> Unparse for ($first, $($rest),*) {
+            fn unparse<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+                self.$i.unparse(writer)?;
+                $(
+                    writeln!(writer)?;
+                    self.$j.unparse(writer)?;
+                )*
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_tuple!(A: 0, B: 1);
+impl_tuple!(A: 0, B: 1, C: 2);
+impl_tuple!(A: 0, B: 1, C: 2, D: 3);
@@ -0,0 +1,118 @@
+use crate::{read_input, Grid, Input, Params, Problem};
+use anyhow::{Context, Result};
+use std::{
+    fmt::Write as _,
+    fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+    thread,
+    time::Duration,
+};
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+}
+
+pub type Frame = Grid<Rgb>;
+
+pub trait Renderer {
+    fn render(&mut self, frame: &Frame) -> Result<()>;
+}
+
+pub trait Visualize: Problem {
+    fn visualize(
+        input: &Self::Input,
+        params: &Self::Params,
+        renderer: &mut dyn Renderer,
+    ) -> Result<()>;
+}
+
+// Animates frames in the terminal, drawing two rows of cells per line with half blocks
+pub struct Ansi {
+    delay: Duration,
+    frames: usize,
+}
+
+impl Ansi {
+    pub fn new(delay: Duration) -> Self {
+        Self { delay, frames: 0 }
+    }
+}
+
+impl Renderer for Ansi {
+    fn render(&mut self, frame: &Frame) -> Result<()> {
+        let mut output = String::new();
+        if self.frames == 0 {
+            output.push_str("\x1b[2J");
+        }
+        output.push_str("\x1b[H");
+        for y in (0..frame.height()).step_by(2) {
+            for x in 0..frame.width() {
+                let Rgb(tr, tg, tb) = frame[(x, y)];
+                let Rgb(br, bg, bb) = frame.get(x, y + 1).copied().unwrap_or_default();
+                write!(
+                    output,
+                    "\x1b[38;2;{};{};{}m\x1b[48;2;{};{};{}m\u{2580}",
+                    tr, tg, tb, br, bg, bb
+                )?;
+            }
+            output.push_str("\x1b[0m\n");
+        }
+        writeln!(output, "Frame {}", self.frames)?;
+
+        let mut stdout = io::stdout().lock();
+        stdout.write_all(output.as_bytes())?;
+        stdout.flush()?;
+        self.frames += 1;
+        thread::sleep(self.delay);
+        Ok(())
+    }
+}
+
+// Writes each frame to a numbered binary PPM image in a directory
+pub struct Ppm {
+    directory: PathBuf,
+    frames: usize,
+}
+
+impl Ppm {
+    pub fn new(directory: &Path) -> Result<Self> {
+        fs::create_dir_all(directory)
+            .with_context(|| format!("Failed to create {}", directory.display()))?;
+        Ok(Self {
+            directory: directory.to_path_buf(),
+            frames: 0,
+        })
+    }
+}
+
+impl Renderer for Ppm {
+    fn render(&mut self, frame: &Frame) -> Result<()> {
+        let mut bytes = format!("P6\n{} {}\n255\n", frame.width(), frame.height()).into_bytes();
+        for &Rgb(r, g, b) in frame.cells() {
+            bytes.extend_from_slice(&[r, g, b]);
+        }
+
+        let path = self.directory.join(format!("frame_{:05}.ppm", self.frames));
+        fs::write(&path, bytes).with_context(|| format!("Failed to write {}", path.display()))?;
+        self.frames += 1;
+        Ok(())
+    }
+}
+
+pub(crate) fn visualize<P: Visualize>(
+    path: &Path,
+    overrides: &[(String, String)],
+    renderer: &mut dyn Renderer,
+) -> Result<()> {
+    let mut params = P::Params::default();
+    for (name, value) in overrides.iter() {
+        params.set(name, value)?;
+    }
+    let input = P::Input::parse(read_input(path)?.as_slice()).context("Failed to parse input")?;
+    P::visualize(&input, &params, renderer)
+}
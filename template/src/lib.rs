@@ -0,0 +1,37 @@
+use anyhow::Result;
+use problem::Problem;
+
+pub struct DayN;
+
+impl Problem for DayN {
+    const DAY: usize = 0;
+
+    type Input = Vec<i32>;
+    type Params = ();
+    type Shared = ();
+    type PartOne = problem::Unimplemented;
+    type PartTwo = problem::Unimplemented;
+
+    fn solve_part_one(
+        _input: &Self::Input,
+        _shared: &Self::Shared,
+        _params: &Self::Params,
+    ) -> Result<Self::PartOne> {
+        Ok(problem::Unimplemented)
+    }
+
+    fn solve_part_two(
+        _input: &Self::Input,
+        _shared: &Self::Shared,
+        _params: &Self::Params,
+    ) -> Result<Self::PartTwo> {
+        Ok(problem::Unimplemented)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DayN;
+
+    problem::example_test!(DayN, "example_1.txt");
+}
@@ -1,20 +1,5 @@
-use problem::{solve_main, Problem};
-
-struct DayN;
-
-impl Problem for DayN {
-    type Input = Vec<i32>;
-    type PartOne = problem::Unimplemented;
-    type PartTwo = problem::Unimplemented;
-
-    fn solve_part_one(_input: &Self::Input) -> Self::PartOne {
-        problem::Unimplemented
-    }
-
-    fn solve_part_two(_input: &Self::Input) -> Self::PartTwo {
-        problem::Unimplemented
-    }
-}
+use template::DayN;
+use problem::solve_main;
 
 fn main() {
     solve_main::<DayN>();